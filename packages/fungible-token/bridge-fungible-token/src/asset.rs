@@ -0,0 +1,76 @@
+use fuels::{
+    tx::ContractIdExt,
+    types::{AssetId, Bits256, Bytes32, ContractId},
+};
+use sha2::{Digest, Sha256};
+
+/// Chain id of Ethereum mainnet
+pub const MAINNET_CHAIN_ID: u64 = 1;
+/// Chain id of the Sepolia testnet
+pub const SEPOLIA_CHAIN_ID: u64 = 11155111;
+
+/// Gets the sub id the bridge mints a base layer token under, following SRC-8:
+/// sha256(chain_id ++ token_address ++ token_id), with the chain id as a decimal string
+pub fn sub_id(l1_chain_id: u64, token_address: Bits256, token_id: Bits256) -> Bits256 {
+    let mut hasher = Sha256::new();
+    hasher.update(l1_chain_id.to_string().as_bytes());
+    hasher.update(token_address.0);
+    hasher.update(token_id.0);
+    Bits256(hasher.finalize().into())
+}
+
+/// Gets the id of the asset the given bridge contract mints for a base layer token
+pub fn bridged_asset_id(
+    bridge_contract: ContractId,
+    l1_chain_id: u64,
+    token_address: Bits256,
+    token_id: Bits256,
+) -> AssetId {
+    let sub_id = sub_id(l1_chain_id, token_address, token_id);
+    bridge_contract.asset_id(&Bytes32::new(sub_id.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIDGE: ContractId = ContractId::new([0x11; 32]);
+    const TOKEN: &str = "0x00000000000000000000000000000000000000000000000000000000deadbeef";
+
+    fn bits(value: &str) -> Bits256 {
+        Bits256::from_hex_str(value).unwrap()
+    }
+
+    // Expected values were computed independently with python's hashlib
+    #[test]
+    fn derives_mainnet_asset_id() {
+        let token_id = Bits256::zeroed();
+
+        assert_eq!(
+            sub_id(MAINNET_CHAIN_ID, bits(TOKEN), token_id),
+            bits("0x70780b5f1fec1e22f97ceb337e23ebfdda44f2820e286d04133af851cc490bd1")
+        );
+        assert_eq!(
+            bridged_asset_id(BRIDGE, MAINNET_CHAIN_ID, bits(TOKEN), token_id),
+            AssetId::new(
+                bits("0x0a8df907901d448db710fdb9003e42ec8fa7076d0e4c03b66e52e674f1785b86").0
+            )
+        );
+    }
+
+    #[test]
+    fn derives_testnet_asset_id() {
+        let token_id = bits("0x0000000000000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(
+            sub_id(SEPOLIA_CHAIN_ID, bits(TOKEN), token_id),
+            bits("0xef548640322dd4c9a2f1d89d510fed63bb2398dbd180e7b4761848ca19941eb5")
+        );
+        assert_eq!(
+            bridged_asset_id(BRIDGE, SEPOLIA_CHAIN_ID, bits(TOKEN), token_id),
+            AssetId::new(
+                bits("0x5371515ad3198b00d7d2c91bdf72cee5c9877da98af3c300c1a22d484263cf29").0
+            )
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use fuels::types::U256;
+
+/// Decimals of the assets minted by the bridge, base layer tokens with more
+/// decimals are scaled down by the gateway
+pub const FUEL_ASSET_DECIMALS: u8 = 9;
+
+/// Reason a deposit would be rejected before anything is minted or refunded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecimalError {
+    /// The gateway only accepts amounts without digits past the Fuel decimals
+    Dust { remainder: U256 },
+    /// The bridge reverts deposits of zero tokens
+    ZeroAmount,
+    /// The scaling factor of the token decimals doesn't fit a uint256
+    UnsupportedDecimals(u8),
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dust { remainder } => write!(
+                f,
+                "amount has a remainder of {remainder} below the Fuel decimals"
+            ),
+            Self::ZeroAmount => write!(f, "amount is zero"),
+            Self::UnsupportedDecimals(decimals) => {
+                write!(f, "{decimals} decimals are not supported")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecimalError {}
+
+/// What the bridge does with a deposit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepositOutcome {
+    /// The amount is minted to the recipient
+    Minted(u64),
+    /// The amount doesn't fit a Fuel amount or the total supply of the asset,
+    /// so a refund of the whole amount, in Fuel decimals, is registered
+    Refunded(U256),
+}
+
+/// Converts amounts of a base layer token to and from the amounts of the asset
+/// the bridge mints for it, as done by the gateway and the bridge contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalAdapter {
+    l1_decimals: u8,
+}
+
+impl DecimalAdapter {
+    pub fn new(l1_decimals: u8) -> Self {
+        Self { l1_decimals }
+    }
+
+    /// Gets the decimals the bridge registers for the asset
+    pub fn l2_decimals(&self) -> u8 {
+        self.l1_decimals.min(FUEL_ASSET_DECIMALS)
+    }
+
+    // Gets the factor between base layer and Fuel amounts
+    fn precision(&self) -> Result<U256, DecimalError> {
+        let exponent = self.l1_decimals.saturating_sub(FUEL_ASSET_DECIMALS);
+        U256::from(10)
+            .checked_pow(exponent.into())
+            .ok_or(DecimalError::UnsupportedDecimals(self.l1_decimals))
+    }
+
+    /// Scales down a base layer amount to the amount sent to the bridge
+    pub fn to_l2_amount(&self, l1_amount: U256) -> Result<U256, DecimalError> {
+        let (amount, remainder) = l1_amount.div_mod(self.precision()?);
+        if !remainder.is_zero() {
+            return Err(DecimalError::Dust { remainder });
+        }
+        Ok(amount)
+    }
+
+    /// Scales up an amount withdrawn or refunded by the bridge to the amount
+    /// released on the base layer, if it fits a uint256
+    pub fn to_l1_amount(&self, l2_amount: U256) -> Option<U256> {
+        l2_amount.checked_mul(self.precision().ok()?)
+    }
+
+    /// Predicts what the bridge does with a deposit of the given base layer
+    /// amount, for an asset with the given total supply
+    pub fn deposit(
+        &self,
+        l1_amount: U256,
+        total_supply: u64,
+    ) -> Result<DepositOutcome, DecimalError> {
+        let amount = self.to_l2_amount(l1_amount)?;
+        if amount.is_zero() {
+            return Err(DecimalError::ZeroAmount);
+        }
+
+        let minted = u64::try_from(amount)
+            .ok()
+            .filter(|amount| total_supply.checked_add(*amount).is_some());
+        Ok(match minted {
+            Some(amount) => DepositOutcome::Minted(amount),
+            None => DepositOutcome::Refunded(amount),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn caps_l2_decimals() {
+        for (l1_decimals, l2_decimals) in [(18, 9), (9, 9), (8, 8), (6, 6), (0, 0)] {
+            assert_eq!(DecimalAdapter::new(l1_decimals).l2_decimals(), l2_decimals);
+        }
+    }
+
+    #[test]
+    fn scales_down_tokens_with_more_decimals() {
+        let adapter = DecimalAdapter::new(18);
+        let one_token = U256::from(10).pow(18.into());
+
+        assert_eq!(
+            adapter.to_l2_amount(one_token),
+            Ok(U256::from(10u64.pow(9)))
+        );
+        assert_eq!(
+            adapter.to_l2_amount(one_token + 1),
+            Err(DecimalError::Dust {
+                remainder: U256::one()
+            })
+        );
+        assert_eq!(
+            adapter.deposit(one_token, 0),
+            Ok(DepositOutcome::Minted(10u64.pow(9)))
+        );
+
+        let adapter = DecimalAdapter::new(6);
+        assert_eq!(adapter.to_l2_amount(U256::from(5)), Ok(U256::from(5)));
+    }
+
+    #[test]
+    fn refunds_overflowing_deposits() {
+        let adapter = DecimalAdapter::new(9);
+
+        assert_eq!(
+            adapter.deposit(U256::from(u64::MAX) + 1, 0),
+            Ok(DepositOutcome::Refunded(U256::from(u64::MAX) + 1))
+        );
+        assert_eq!(
+            adapter.deposit(U256::from(2), u64::MAX - 1),
+            Ok(DepositOutcome::Refunded(U256::from(2)))
+        );
+        assert_eq!(
+            adapter.deposit(U256::from(1), u64::MAX - 1),
+            Ok(DepositOutcome::Minted(1))
+        );
+        assert_eq!(
+            adapter.deposit(U256::zero(), 0),
+            Err(DecimalError::ZeroAmount)
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_decimals() {
+        assert_eq!(
+            DecimalAdapter::new(87).to_l2_amount(U256::one()),
+            Err(DecimalError::UnsupportedDecimals(87))
+        );
+        assert!(DecimalAdapter::new(86).to_l2_amount(U256::zero()).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn withdrawals_restore_deposits(l1_decimals in 0u8..=40, l2_amount: u128) {
+            let adapter = DecimalAdapter::new(l1_decimals);
+            let l1_amount = adapter.to_l1_amount(U256::from(l2_amount)).unwrap();
+
+            prop_assert_eq!(adapter.to_l2_amount(l1_amount), Ok(U256::from(l2_amount)));
+        }
+
+        #[test]
+        fn deposits_mint_or_refund_the_whole_amount(
+            l1_decimals in 0u8..=40,
+            l2_amount in 1u128..,
+            total_supply: u64,
+        ) {
+            let adapter = DecimalAdapter::new(l1_decimals);
+            let l1_amount = adapter.to_l1_amount(U256::from(l2_amount)).unwrap();
+
+            match adapter.deposit(l1_amount, total_supply).unwrap() {
+                DepositOutcome::Minted(amount) => {
+                    prop_assert_eq!(u128::from(amount), l2_amount);
+                    prop_assert!(total_supply.checked_add(amount).is_some());
+                }
+                DepositOutcome::Refunded(amount) => {
+                    prop_assert_eq!(amount, U256::from(l2_amount));
+                    prop_assert!(u64::try_from(l2_amount)
+                        .ok()
+                        .and_then(|amount| total_supply.checked_add(amount))
+                        .is_none());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use fuels::{
+    core::{
+        codec::ABIDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
+    macros::{Parameterize, Tokenizable},
+    tx::Receipt,
+    types::{AssetId, Bits256, Bytes, ContractId, Identity, U256},
+};
+use sha2::{Digest, Sha256};
+
+// Types of the logged events, as written in the contract ABI
+const REFUND_REGISTERED_EVENT: &str = "struct events::RefundRegisteredEvent";
+const DEPOSIT_EVENT: &str = "struct events::DepositEvent";
+const WITHDRAWAL_EVENT: &str = "struct events::WithdrawalEvent";
+const CLAIM_REFUND_EVENT: &str = "struct events::ClaimRefundEvent";
+const SET_NAME_EVENT: &str = "struct standards::src20::SetNameEvent";
+const SET_SYMBOL_EVENT: &str = "struct standards::src20::SetSymbolEvent";
+const SET_DECIMALS_EVENT: &str = "struct standards::src20::SetDecimalsEvent";
+const TOTAL_SUPPLY_EVENT: &str = "struct standards::src20::TotalSupplyEvent";
+const SET_METADATA_EVENT: &str = "struct standards::src7::SetMetadataEvent";
+
+/// A deposit that didn't fit the asset amounts, claimable on the base layer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundRegisteredEvent {
+    pub amount: U256,
+    pub token_address: Bits256,
+    pub token_id: Bits256,
+    pub from: Bits256,
+}
+
+// Refund as logged by the bridge, with the amount as a b256
+#[derive(Parameterize, Tokenizable)]
+struct LoggedRefundRegisteredEvent {
+    amount: Bits256,
+    token_address: Bits256,
+    token_id: Bits256,
+    from: Bits256,
+}
+
+impl From<LoggedRefundRegisteredEvent> for RefundRegisteredEvent {
+    fn from(event: LoggedRefundRegisteredEvent) -> Self {
+        Self {
+            amount: U256::from_big_endian(&event.amount.0),
+            token_address: event.token_address,
+            token_id: event.token_id,
+            from: event.from,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct DepositEvent {
+    pub amount: u64,
+    pub from: Bits256,
+    pub to: Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct WithdrawalEvent {
+    pub amount: u64,
+    pub from: Identity,
+    pub to: Bits256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct ClaimRefundEvent {
+    pub amount: U256,
+    pub from: Bits256,
+    pub token_address: Bits256,
+    pub token_id: Bits256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct SetNameEvent {
+    pub asset: AssetId,
+    pub name: Option<String>,
+    pub sender: Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct SetSymbolEvent {
+    pub asset: AssetId,
+    pub symbol: Option<String>,
+    pub sender: Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct SetDecimalsEvent {
+    pub asset: AssetId,
+    pub decimals: u8,
+    pub sender: Identity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct TotalSupplyEvent {
+    pub asset: AssetId,
+    pub supply: u64,
+    pub sender: Identity,
+}
+
+/// Metadata value of SRC-7
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub enum Metadata {
+    B256(Bits256),
+    Bytes(Bytes),
+    Int(u64),
+    String(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parameterize, Tokenizable)]
+pub struct SetMetadataEvent {
+    pub asset: AssetId,
+    pub metadata: Option<Metadata>,
+    pub key: String,
+    pub sender: Identity,
+}
+
+/// An event logged by the bridge contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeEvent {
+    RefundRegistered(RefundRegisteredEvent),
+    Deposit(DepositEvent),
+    Withdrawal(WithdrawalEvent),
+    ClaimRefund(ClaimRefundEvent),
+    SetName(SetNameEvent),
+    SetSymbol(SetSymbolEvent),
+    SetDecimals(SetDecimalsEvent),
+    TotalSupply(TotalSupplyEvent),
+    SetMetadata(SetMetadataEvent),
+}
+
+impl BridgeEvent {
+    /// Decodes the events logged by the given bridge contract in the receipts,
+    /// in order. Logs of other contracts and other logs of the bridge are
+    /// skipped, and so are logs that fail to decode as the event they are
+    /// tagged with, without an error.
+    pub fn from_receipts(bridge: ContractId, receipts: &[Receipt]) -> Vec<Self> {
+        receipts
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::LogData {
+                    id,
+                    rb,
+                    data: Some(data),
+                    ..
+                } if *id == bridge => Self::decode(*rb, data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Decodes the data of a log with the given log id
+    fn decode(log_id: u64, data: &[u8]) -> Option<Self> {
+        let event = match log_id {
+            id if id == self::log_id(REFUND_REGISTERED_EVENT) => {
+                Self::RefundRegistered(decode::<LoggedRefundRegisteredEvent>(data)?.into())
+            }
+            id if id == self::log_id(DEPOSIT_EVENT) => Self::Deposit(decode(data)?),
+            id if id == self::log_id(WITHDRAWAL_EVENT) => Self::Withdrawal(decode(data)?),
+            id if id == self::log_id(CLAIM_REFUND_EVENT) => Self::ClaimRefund(decode(data)?),
+            id if id == self::log_id(SET_NAME_EVENT) => Self::SetName(decode(data)?),
+            id if id == self::log_id(SET_SYMBOL_EVENT) => Self::SetSymbol(decode(data)?),
+            id if id == self::log_id(SET_DECIMALS_EVENT) => Self::SetDecimals(decode(data)?),
+            id if id == self::log_id(TOTAL_SUPPLY_EVENT) => Self::TotalSupply(decode(data)?),
+            id if id == self::log_id(SET_METADATA_EVENT) => Self::SetMetadata(decode(data)?),
+            _ => return None,
+        };
+        Some(event)
+    }
+}
+
+/// Gets the id a type is logged with: the first 8 bytes of its concrete type
+/// id in the ABI, which is the sha256 hash of the type
+pub fn log_id(abi_type: &str) -> u64 {
+    let hash = Sha256::digest(abi_type.as_bytes());
+    u64::from_be_bytes(hash[..8].try_into().expect("hash is 32 bytes"))
+}
+
+fn decode<T: Parameterize + Tokenizable>(data: &[u8]) -> Option<T> {
+    let token = ABIDecoder::default().decode(&T::param_type(), data).ok()?;
+    T::from_token(token).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::core::codec::ABIEncoder;
+
+    use super::*;
+
+    const BRIDGE: ContractId = ContractId::new([0x11; 32]);
+
+    fn log(abi_type: &str, event: impl Tokenizable) -> Receipt {
+        log_from(BRIDGE, abi_type, event)
+    }
+
+    fn log_from(contract_id: ContractId, abi_type: &str, event: impl Tokenizable) -> Receipt {
+        let data = ABIEncoder::default().encode(&[event.into_token()]).unwrap();
+        Receipt::log_data(contract_id, 0, log_id(abi_type), 0, 0, 0, data)
+    }
+
+    #[test]
+    fn log_id_matches_abi() {
+        // concrete type id of u64 in the ABI of the contract message test contract
+        assert_eq!(log_id("u64"), 0x1506e6f44c1d6291);
+    }
+
+    #[test]
+    fn decodes_deposit_events() {
+        let asset = AssetId::new([0x22; 32]);
+        let sender = Identity::Address([0x33; 32].into());
+        let receipts = [
+            log(
+                SET_METADATA_EVENT,
+                SetMetadataEvent {
+                    asset,
+                    metadata: Some(Metadata::String("1".to_string())),
+                    key: "bridged:chain".to_string(),
+                    sender,
+                },
+            ),
+            log(
+                SET_DECIMALS_EVENT,
+                SetDecimalsEvent {
+                    asset,
+                    decimals: 9,
+                    sender,
+                },
+            ),
+            Receipt::log(BRIDGE, 42, 0, 0, 0, 0, 0),
+            log(
+                TOTAL_SUPPLY_EVENT,
+                TotalSupplyEvent {
+                    asset,
+                    supply: 100,
+                    sender,
+                },
+            ),
+            log(
+                DEPOSIT_EVENT,
+                DepositEvent {
+                    amount: 100,
+                    from: Bits256([0x44; 32]),
+                    to: Identity::ContractId(BRIDGE),
+                },
+            ),
+        ];
+
+        assert_eq!(
+            BridgeEvent::from_receipts(BRIDGE, &receipts),
+            vec![
+                BridgeEvent::SetMetadata(SetMetadataEvent {
+                    asset,
+                    metadata: Some(Metadata::String("1".to_string())),
+                    key: "bridged:chain".to_string(),
+                    sender,
+                }),
+                BridgeEvent::SetDecimals(SetDecimalsEvent {
+                    asset,
+                    decimals: 9,
+                    sender,
+                }),
+                BridgeEvent::TotalSupply(TotalSupplyEvent {
+                    asset,
+                    supply: 100,
+                    sender,
+                }),
+                BridgeEvent::Deposit(DepositEvent {
+                    amount: 100,
+                    from: Bits256([0x44; 32]),
+                    to: Identity::ContractId(BRIDGE),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_refund_amount() {
+        let amount = U256::from(u64::MAX) + 1;
+        let mut amount_bytes = [0u8; 32];
+        amount.to_big_endian(&mut amount_bytes);
+
+        let receipts = [log(
+            REFUND_REGISTERED_EVENT,
+            LoggedRefundRegisteredEvent {
+                amount: Bits256(amount_bytes),
+                token_address: Bits256([0x55; 32]),
+                token_id: Bits256::zeroed(),
+                from: Bits256([0x44; 32]),
+            },
+        )];
+
+        assert_eq!(
+            BridgeEvent::from_receipts(BRIDGE, &receipts),
+            vec![BridgeEvent::RefundRegistered(RefundRegisteredEvent {
+                amount,
+                token_address: Bits256([0x55; 32]),
+                token_id: Bits256::zeroed(),
+                from: Bits256([0x44; 32]),
+            })]
+        );
+    }
+
+    #[test]
+    fn skips_undecodable_logs() {
+        let receipts = [Receipt::log_data(
+            BRIDGE,
+            0,
+            log_id(DEPOSIT_EVENT),
+            0,
+            0,
+            0,
+            vec![1, 2, 3],
+        )];
+
+        assert!(BridgeEvent::from_receipts(BRIDGE, &receipts).is_empty());
+    }
+
+    #[test]
+    fn skips_logs_of_other_contracts() {
+        let deposit = DepositEvent {
+            amount: 100,
+            from: Bits256([0x44; 32]),
+            to: Identity::ContractId(BRIDGE),
+        };
+        let receipts = [
+            log_from(ContractId::new([0x66; 32]), DEPOSIT_EVENT, deposit.clone()),
+            log(DEPOSIT_EVENT, deposit.clone()),
+        ];
+
+        assert_eq!(
+            BridgeEvent::from_receipts(BRIDGE, &receipts),
+            vec![BridgeEvent::Deposit(deposit)]
+        );
+    }
+}
@@ -0,0 +1,11 @@
+pub mod asset;
+pub mod decimals;
+pub mod events;
+pub mod message;
+pub mod withdrawal;
+
+pub use asset::{bridged_asset_id, sub_id};
+pub use decimals::{DecimalAdapter, DecimalError, DepositOutcome};
+pub use events::BridgeEvent;
+pub use message::{BridgeMessage, DepositMessage, DepositType, MessageError, MetadataMessage};
+pub use withdrawal::{WithdrawalError, WithdrawalMessage};
@@ -0,0 +1,403 @@
+use std::fmt;
+
+use ethers::abi::Token;
+use fuels::types::{Address, Bits256, ContractId, Identity, U256};
+
+// A message is encoded as
+// 0x00 => CONTRACT_ID
+// 0x20 => MESSAGE_TYPE
+// 0x40 => TOKEN_ADDRESS
+// 0x60 => TOKEN_ID
+pub const OFFSET_MESSAGE_TYPE: usize = 32;
+pub const OFFSET_TOKEN_ADDRESS: usize = OFFSET_MESSAGE_TYPE + 32;
+pub const OFFSET_TOKEN_ID: usize = OFFSET_TOKEN_ADDRESS + 32;
+
+// 0x80 and onwards: payload, with offsets defined as below
+
+// Offsets for a deposit message
+pub const OFFSET_FROM: usize = OFFSET_TOKEN_ID + 32;
+pub const OFFSET_TO: usize = OFFSET_FROM + 32;
+pub const OFFSET_AMOUNT: usize = OFFSET_TO + 32;
+pub const OFFSET_DECIMALS: usize = OFFSET_AMOUNT + 32;
+// Extra data passed along to the receiving contract of a deposit with data. The
+// bridge contract doesn't read it, so it has no counterpart in constants.sw: it is
+// the `data` packed by `FuelERC20GatewayV4.depositWithData` after the decimals
+pub const OFFSET_DEPOSIT_DATA: usize = OFFSET_DECIMALS + 32;
+
+// Offsets for a metadata message
+pub const OFFSET_NAME_PTR: usize = OFFSET_TOKEN_ID + 32;
+pub const OFFSET_SYMBOL_PTR: usize = OFFSET_NAME_PTR + 32;
+
+// Type of messages that can be received
+pub const DEPOSIT: u8 = 0;
+pub const CONTRACT_DEPOSIT: u8 = 1;
+pub const CONTRACT_DEPOSIT_WITH_DATA: u8 = 2;
+pub const METADATA: u8 = 3;
+
+const SLOT_SIZE: usize = 32;
+
+/// Reason the data of a message can't be decoded as a bridge message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageError {
+    /// The data ends before the end of a field
+    TooShort { expected: usize, actual: usize },
+    /// The message type is not one the bridge accepts
+    UnknownType(u8),
+    /// A string of a metadata message points outside of the data
+    StringOutOfBounds { offset: usize },
+    /// A string of a metadata message is not valid UTF-8
+    InvalidUtf8 { offset: usize },
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { expected, actual } => write!(
+                f,
+                "message data is {actual} bytes long, expected at least {expected}"
+            ),
+            Self::UnknownType(message_type) => write!(f, "unknown message type {message_type}"),
+            Self::StringOutOfBounds { offset } => {
+                write!(f, "string at offset {offset} is out of bounds")
+            }
+            Self::InvalidUtf8 { offset } => write!(f, "string at offset {offset} is not UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for MessageError {}
+
+/// Recipient kind of a deposit, mirroring the deposit message types
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepositType {
+    Address,
+    Contract,
+    /// Deposit to a contract that also gets the given data
+    ContractWithData(Vec<u8>),
+}
+
+/// Deposit of tokens from the base layer gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositMessage {
+    pub token_address: Bits256,
+    pub token_id: Bits256,
+    pub from: Bits256,
+    pub to: Bits256,
+    pub amount: U256,
+    pub decimals: u8,
+    pub deposit_type: DepositType,
+}
+
+impl DepositMessage {
+    /// Gets the recipient of the deposit, as the bridge reads it
+    pub fn recipient(&self) -> Identity {
+        match self.deposit_type {
+            DepositType::Address => Identity::Address(Address::new(self.to.0)),
+            DepositType::Contract | DepositType::ContractWithData(_) => {
+                Identity::ContractId(ContractId::new(self.to.0))
+            }
+        }
+    }
+
+    fn message_type(&self) -> u8 {
+        match self.deposit_type {
+            DepositType::Address => DEPOSIT,
+            DepositType::Contract => CONTRACT_DEPOSIT,
+            DepositType::ContractWithData(_) => CONTRACT_DEPOSIT_WITH_DATA,
+        }
+    }
+}
+
+/// Name and symbol of a token, sent by the base layer gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataMessage {
+    pub token_address: Bits256,
+    pub token_id: Bits256,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// A message the bridge contract receives from the base layer gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeMessage {
+    Deposit(DepositMessage),
+    Metadata(MetadataMessage),
+}
+
+impl BridgeMessage {
+    /// Encodes the message as the data of a message sent to the given bridge contract
+    pub fn encode(&self, bridge: ContractId) -> Vec<u8> {
+        let mut data: Vec<u8> = bridge.to_vec();
+
+        match self {
+            Self::Deposit(deposit) => {
+                data.extend_from_slice(&u256_slot(deposit.message_type().into()));
+                data.extend_from_slice(&deposit.token_address.0);
+                data.extend_from_slice(&deposit.token_id.0);
+                data.extend_from_slice(&deposit.from.0);
+                data.extend_from_slice(&deposit.to.0);
+                data.extend_from_slice(&u256_slot(deposit.amount));
+                data.extend_from_slice(&u256_slot(deposit.decimals.into()));
+                if let DepositType::ContractWithData(extra) = &deposit.deposit_type {
+                    data.extend_from_slice(extra);
+                }
+            }
+            Self::Metadata(metadata) => {
+                data.extend_from_slice(&u256_slot(METADATA.into()));
+                // The rest of the payload is ABI encoded by the gateway
+                data.append(&mut ethers::abi::encode(&[
+                    Token::FixedBytes(metadata.token_address.0.to_vec()),
+                    Token::FixedBytes(metadata.token_id.0.to_vec()),
+                    Token::String(metadata.name.clone()),
+                    Token::String(metadata.symbol.clone()),
+                ]));
+            }
+        }
+
+        data
+    }
+
+    /// Decodes the data of a message the same way the bridge contract does,
+    /// returning the bridge contract the message is sent to
+    pub fn decode(data: &[u8]) -> Result<(ContractId, Self), MessageError> {
+        let bridge = ContractId::new(read_slot(data, 0)?);
+        // Only the last byte of the type is read
+        let message_type = read_slot(data, OFFSET_MESSAGE_TYPE)?[SLOT_SIZE - 1];
+
+        let message = match message_type {
+            DEPOSIT | CONTRACT_DEPOSIT | CONTRACT_DEPOSIT_WITH_DATA => {
+                let deposit_type = match message_type {
+                    DEPOSIT => DepositType::Address,
+                    CONTRACT_DEPOSIT => DepositType::Contract,
+                    _ => DepositType::ContractWithData(
+                        data.get(OFFSET_DEPOSIT_DATA..).unwrap_or_default().to_vec(),
+                    ),
+                };
+                Self::Deposit(DepositMessage {
+                    token_address: Bits256(read_slot(data, OFFSET_TOKEN_ADDRESS)?),
+                    token_id: Bits256(read_slot(data, OFFSET_TOKEN_ID)?),
+                    from: Bits256(read_slot(data, OFFSET_FROM)?),
+                    to: Bits256(read_slot(data, OFFSET_TO)?),
+                    amount: U256::from_big_endian(&read_slot(data, OFFSET_AMOUNT)?),
+                    decimals: read_slot(data, OFFSET_DECIMALS)?[SLOT_SIZE - 1],
+                    deposit_type,
+                })
+            }
+            METADATA => Self::Metadata(decode_metadata(data)?),
+            _ => return Err(MessageError::UnknownType(message_type)),
+        };
+
+        Ok((bridge, message))
+    }
+}
+
+// Decodes the ABI encoded payload of a metadata message. String pointers
+// are relative to the token address, where the payload starts.
+fn decode_metadata(data: &[u8]) -> Result<MetadataMessage, MessageError> {
+    let name_ptr = read_offset(data, OFFSET_NAME_PTR)?;
+    let symbol_ptr = read_offset(data, OFFSET_SYMBOL_PTR)?;
+
+    Ok(MetadataMessage {
+        token_address: Bits256(read_slot(data, OFFSET_TOKEN_ADDRESS)?),
+        token_id: Bits256(read_slot(data, OFFSET_TOKEN_ID)?),
+        name: read_string(data, name_ptr)?,
+        symbol: read_string(data, symbol_ptr)?,
+    })
+}
+
+// Reads a string encoded as its length followed by its bytes
+fn read_string(data: &[u8], ptr: usize) -> Result<String, MessageError> {
+    let offset = OFFSET_TOKEN_ADDRESS
+        .checked_add(ptr)
+        .ok_or(MessageError::StringOutOfBounds { offset: ptr })?;
+    let len = read_offset(data, offset).map_err(|_| MessageError::StringOutOfBounds { offset })?;
+    let bytes = offset
+        .checked_add(SLOT_SIZE)
+        .and_then(|start| data.get(start..start.checked_add(len)?))
+        .ok_or(MessageError::StringOutOfBounds { offset })?;
+
+    String::from_utf8(bytes.to_vec()).map_err(|_| MessageError::InvalidUtf8 { offset })
+}
+
+// Reads a slot holding an offset or a length
+fn read_offset(data: &[u8], offset: usize) -> Result<usize, MessageError> {
+    let value = U256::from_big_endian(&read_slot(data, offset)?);
+    usize::try_from(value).map_err(|_| MessageError::StringOutOfBounds { offset })
+}
+
+fn read_slot(data: &[u8], offset: usize) -> Result<[u8; SLOT_SIZE], MessageError> {
+    data.get(offset..offset + SLOT_SIZE)
+        .map(|slot| slot.try_into().expect("slot is 32 bytes"))
+        .ok_or(MessageError::TooShort {
+            expected: offset + SLOT_SIZE,
+            actual: data.len(),
+        })
+}
+
+fn u256_slot(value: U256) -> [u8; SLOT_SIZE] {
+    let mut slot = [0u8; SLOT_SIZE];
+    value.to_big_endian(&mut slot);
+    slot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Values used by the integration tests
+    const BRIDGE: [u8; 32] = [0x11; 32];
+    const BRIDGED_TOKEN: &str =
+        "0x00000000000000000000000000000000000000000000000000000000deadbeef";
+    const BRIDGED_TOKEN_ID: &str =
+        "0x0000000000000000000000000000000000000000000000000000000000000000";
+    const FROM: &str = "0x0000000000000000000000008888888888888888888888888888888888888888";
+    const TO: &str = "0x0000000000000000000000000000000000000000000000000000000000000777";
+
+    fn bits(value: &str) -> Bits256 {
+        Bits256::from_hex_str(value).unwrap()
+    }
+
+    fn deposit(deposit_type: DepositType) -> DepositMessage {
+        DepositMessage {
+            token_address: bits(BRIDGED_TOKEN),
+            token_id: bits(BRIDGED_TOKEN_ID),
+            from: bits(FROM),
+            to: bits(TO),
+            amount: U256::from(u64::MAX),
+            decimals: 18,
+            deposit_type,
+        }
+    }
+
+    // Data of a deposit of u64::MAX with 18 decimals, slot by slot as packed by
+    // `FuelERC20GatewayV4.deposit` and `depositWithData`
+    fn deposit_vector(message_type: &str, extra: &str) -> Vec<u8> {
+        let data = [
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            message_type,
+            "00000000000000000000000000000000000000000000000000000000deadbeef",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000008888888888888888888888888888888888888888",
+            "0000000000000000000000000000000000000000000000000000000000000777",
+            "000000000000000000000000000000000000000000000000ffffffffffffffff",
+            "0000000000000000000000000000000000000000000000000000000000000012",
+            extra,
+        ]
+        .concat();
+        hex::decode(data).unwrap()
+    }
+
+    #[test]
+    fn deposits_match_test_vectors() {
+        let cases = [
+            (
+                DepositType::Address,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "",
+            ),
+            (
+                DepositType::Contract,
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "",
+            ),
+            (
+                DepositType::ContractWithData(vec![1, 2, 3]),
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "010203",
+            ),
+        ];
+
+        for (deposit_type, message_type, extra) in cases {
+            let message = BridgeMessage::Deposit(deposit(deposit_type));
+            let data = message.encode(ContractId::new(BRIDGE));
+
+            assert_eq!(data, deposit_vector(message_type, extra));
+            assert_eq!(
+                BridgeMessage::decode(&data).unwrap(),
+                (ContractId::new(BRIDGE), message)
+            );
+        }
+    }
+
+    #[test]
+    fn deposit_recipient_follows_type() {
+        let to = bits(TO).0;
+
+        assert_eq!(
+            deposit(DepositType::Address).recipient(),
+            Identity::Address(Address::new(to))
+        );
+        assert_eq!(
+            deposit(DepositType::ContractWithData(vec![])).recipient(),
+            Identity::ContractId(ContractId::new(to))
+        );
+    }
+
+    #[test]
+    fn metadata_matches_test_vector() {
+        let message = BridgeMessage::Metadata(MetadataMessage {
+            token_address: bits(BRIDGED_TOKEN),
+            token_id: bits(BRIDGED_TOKEN_ID),
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+        });
+        let data = message.encode(ContractId::new(BRIDGE));
+
+        let expected = [
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "00000000000000000000000000000000000000000000000000000000deadbeef",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "00000000000000000000000000000000000000000000000000000000000000c0",
+            "0000000000000000000000000000000000000000000000000000000000000005",
+            "546f6b656e000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "544b4e0000000000000000000000000000000000000000000000000000000000",
+        ]
+        .concat();
+        assert_eq!(hex::encode(&data), expected);
+        assert_eq!(
+            BridgeMessage::decode(&data).unwrap(),
+            (ContractId::new(BRIDGE), message)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_messages() {
+        let data = deposit_vector(
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "",
+        );
+        assert_eq!(
+            BridgeMessage::decode(&data[..OFFSET_DECIMALS]),
+            Err(MessageError::TooShort {
+                expected: OFFSET_DECIMALS + 32,
+                actual: OFFSET_DECIMALS
+            })
+        );
+        assert_eq!(
+            BridgeMessage::decode(&deposit_vector(
+                "0000000000000000000000000000000000000000000000000000000000000004",
+                ""
+            )),
+            Err(MessageError::UnknownType(4))
+        );
+
+        // a name pointing past the end of the data
+        let mut data = BridgeMessage::Metadata(MetadataMessage {
+            token_address: bits(BRIDGED_TOKEN),
+            token_id: bits(BRIDGED_TOKEN_ID),
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+        })
+        .encode(ContractId::new(BRIDGE));
+        data[OFFSET_NAME_PTR + 31] = 0xff;
+        assert_eq!(
+            BridgeMessage::decode(&data),
+            Err(MessageError::StringOutOfBounds {
+                offset: OFFSET_TOKEN_ADDRESS + 0xff
+            })
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use fuels::types::{Bits256, U256};
+
+/// Signature of the function finalizing withdrawals on the base layer gateway
+pub const FINALIZE_WITHDRAWAL_SIGNATURE: &str =
+    "finalizeWithdrawal(address,address,uint256,uint256)";
+/// Selector of `finalizeWithdrawal`, the first 4 bytes of the keccak256 hash of its signature
+pub const FINALIZE_WITHDRAWAL_SELECTOR: [u8; 4] = [0x64, 0xa7, 0xfa, 0xd9];
+
+// Selector followed by to, token, amount and token_id
+pub const WITHDRAWAL_MESSAGE_LEN: usize = 4 + 32 + 32 + 32 + 32;
+
+/// Reason the data of an output message can't be decoded as a withdrawal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WithdrawalError {
+    /// The data is not as long as a withdrawal message
+    InvalidLength { expected: usize, actual: usize },
+    /// The data doesn't call `finalizeWithdrawal` on the gateway
+    WrongSelector([u8; 4]),
+}
+
+impl fmt::Display for WithdrawalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "withdrawal message is {actual} bytes long instead of {expected}"
+            ),
+            Self::WrongSelector(selector) => write!(
+                f,
+                "selector 0x{} is not finalizeWithdrawal",
+                hex::encode(selector)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WithdrawalError {}
+
+/// Message sent by the bridge to the base layer gateway when tokens are
+/// withdrawn or refunded, calling `finalizeWithdrawal`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalMessage {
+    /// Recipient on the base layer, left padded to 32 bytes
+    pub to: Bits256,
+    pub token: Bits256,
+    pub amount: U256,
+    pub token_id: Bits256,
+}
+
+impl WithdrawalMessage {
+    /// Encodes the message data, as done by the bridge contract
+    pub fn encode(&self) -> Vec<u8> {
+        let mut amount = [0u8; 32];
+        self.amount.to_big_endian(&mut amount);
+
+        let mut data = Vec::with_capacity(WITHDRAWAL_MESSAGE_LEN);
+        data.extend_from_slice(&FINALIZE_WITHDRAWAL_SELECTOR);
+        data.extend_from_slice(&self.to.0);
+        data.extend_from_slice(&self.token.0);
+        data.extend_from_slice(&amount);
+        data.extend_from_slice(&self.token_id.0);
+        data
+    }
+
+    /// Decodes the data of a message sent by the bridge contract
+    pub fn decode(data: &[u8]) -> Result<Self, WithdrawalError> {
+        if data.len() != WITHDRAWAL_MESSAGE_LEN {
+            return Err(WithdrawalError::InvalidLength {
+                expected: WITHDRAWAL_MESSAGE_LEN,
+                actual: data.len(),
+            });
+        }
+
+        let (selector, fields) = data.split_at(4);
+        if selector != FINALIZE_WITHDRAWAL_SELECTOR {
+            return Err(WithdrawalError::WrongSelector(
+                selector.try_into().expect("selector is 4 bytes"),
+            ));
+        }
+
+        let slot = |index: usize| -> [u8; 32] {
+            fields[index * 32..(index + 1) * 32]
+                .try_into()
+                .expect("slot is 32 bytes")
+        };
+        Ok(Self {
+            to: Bits256(slot(0)),
+            token: Bits256(slot(1)),
+            amount: U256::from_big_endian(&slot(2)),
+            token_id: Bits256(slot(3)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha3::{Digest, Keccak256};
+
+    use super::*;
+
+    fn withdrawal() -> WithdrawalMessage {
+        WithdrawalMessage {
+            to: Bits256([0x88; 32]),
+            token: Bits256([0xde; 32]),
+            amount: U256::from(10).pow(U256::from(18)),
+            token_id: Bits256([0x01; 32]),
+        }
+    }
+
+    #[test]
+    fn selector_matches_signature() {
+        let hash = Keccak256::digest(FINALIZE_WITHDRAWAL_SIGNATURE.as_bytes());
+        assert_eq!(hash[..4], FINALIZE_WITHDRAWAL_SELECTOR);
+    }
+
+    #[test]
+    fn decodes_encoded_withdrawal() {
+        let data = withdrawal().encode();
+
+        assert_eq!(data.len(), WITHDRAWAL_MESSAGE_LEN);
+        assert_eq!(data[..4], [0x64, 0xa7, 0xfa, 0xd9]);
+        assert_eq!(data[4..36], [0x88; 32]);
+        assert_eq!(data[36..68], [0xde; 32]);
+        assert_eq!(data[92..100], 10u64.pow(18).to_be_bytes());
+        assert_eq!(data[100..132], [0x01; 32]);
+        assert_eq!(WithdrawalMessage::decode(&data), Ok(withdrawal()));
+    }
+
+    #[test]
+    fn rejects_malformed_withdrawals() {
+        let mut data = withdrawal().encode();
+
+        assert_eq!(
+            WithdrawalMessage::decode(&data[..100]),
+            Err(WithdrawalError::InvalidLength {
+                expected: WITHDRAWAL_MESSAGE_LEN,
+                actual: 100
+            })
+        );
+
+        data[0] = 0;
+        assert_eq!(
+            WithdrawalMessage::decode(&data),
+            Err(WithdrawalError::WrongSelector([0x00, 0xa7, 0xfa, 0xd9]))
+        );
+    }
+}
@@ -2,11 +2,11 @@ use crate::utils::{
     constants::{BRIDGED_TOKEN, BRIDGED_TOKEN_DECIMALS, BRIDGED_TOKEN_ID, FROM},
     interface::bridge::withdraw,
     setup::{
-        create_deposit_message, create_wallet, decode_hex, encode_hex, parse_output_message_data,
-        relay_message_to_contract, setup_environment, wallet_balance,
-        BridgeFungibleTokenContractConfigurables,
+        create_deposit_message, create_wallet, encode_hex, relay_message_to_contract,
+        setup_environment, wallet_balance, BridgeFungibleTokenContractConfigurables,
     },
 };
+use bridge_fungible_token_contract::WithdrawalMessage;
 use fuels::{prelude::AssetId, types::Bits256};
 
 mod success {
@@ -146,16 +146,20 @@ mod success {
         assert_eq!(message_receipt.len().unwrap(), 132);
 
         // message data
-        let (selector, to, token, amount, token_id) =
-            parse_output_message_data(message_receipt.data().unwrap());
+        let withdrawal = WithdrawalMessage::decode(message_receipt.data().unwrap()).unwrap();
 
-        assert_eq!(selector, decode_hex("0x64a7fad9").to_vec());
-        assert_eq!(to, Bits256::from_hex_str(FROM).unwrap());
-        assert_eq!(token, Bits256::from_hex_str(&token_address).unwrap());
-        assert_eq!(token_id, Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap());
+        assert_eq!(withdrawal.to, Bits256::from_hex_str(FROM).unwrap());
+        assert_eq!(
+            withdrawal.token,
+            Bits256::from_hex_str(&token_address).unwrap()
+        );
+        assert_eq!(
+            withdrawal.token_id,
+            Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap()
+        );
 
         // Compare the value output in the message with the original value sent
-        assert_eq!(amount, deposit_amount);
+        assert_eq!(withdrawal.amount, deposit_amount);
     }
 
     #[tokio::test]
@@ -297,16 +301,20 @@ mod success {
         assert_eq!(message_receipt.len().unwrap(), 132);
 
         // message data
-        let (selector, to, token, amount, token_id) =
-            parse_output_message_data(message_receipt.data().unwrap());
+        let withdrawal = WithdrawalMessage::decode(message_receipt.data().unwrap()).unwrap();
 
-        assert_eq!(selector, decode_hex("0x64a7fad9").to_vec());
-        assert_eq!(to, Bits256::from_hex_str(FROM).unwrap());
-        assert_eq!(token, Bits256::from_hex_str(&token_address).unwrap());
-        assert_eq!(token_id, Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap());
+        assert_eq!(withdrawal.to, Bits256::from_hex_str(FROM).unwrap());
+        assert_eq!(
+            withdrawal.token,
+            Bits256::from_hex_str(&token_address).unwrap()
+        );
+        assert_eq!(
+            withdrawal.token_id,
+            Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap()
+        );
 
         // Compare the value output in the message with the original value sent
-        assert_eq!(amount, deposit_amount);
+        assert_eq!(withdrawal.amount, deposit_amount);
     }
 
     #[tokio::test]
@@ -385,8 +393,7 @@ mod success {
             .find(|&r| matches!(r, Receipt::MessageOut { .. }))
             .unwrap();
 
-        let (selector, to, token, amount, token_id) =
-            parse_output_message_data(message_receipt.data().unwrap());
+        let withdrawal = WithdrawalMessage::decode(message_receipt.data().unwrap()).unwrap();
 
         assert_eq!(
             *bridge.contract_id().hash(),
@@ -400,11 +407,16 @@ mod success {
         assert_eq!(message_receipt.len().unwrap(), 132);
 
         // message data
-        assert_eq!(selector, decode_hex("0x64a7fad9").to_vec());
-        assert_eq!(to, Bits256(*wallet.address().hash()));
-        assert_eq!(token, Bits256::from_hex_str(BRIDGED_TOKEN).unwrap());
-        assert_eq!(token_id, Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap());
-        assert_eq!(amount, amount);
+        assert_eq!(withdrawal.to, Bits256(*wallet.address().hash()));
+        assert_eq!(
+            withdrawal.token,
+            Bits256::from_hex_str(BRIDGED_TOKEN).unwrap()
+        );
+        assert_eq!(
+            withdrawal.token_id,
+            Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap()
+        );
+        assert_eq!(withdrawal.amount, U256::from(amount));
 
         // Check that supply has decreased by withdrawal_amount
         let supply = total_supply(
@@ -21,15 +21,17 @@ mod success {
         get_asset_id, get_contract_ids, precalculate_deposit_id, wallet_balance,
         RefundRegisteredEvent,
     };
+    use bridge_fungible_token_contract::{BridgeEvent, DecimalAdapter, DepositOutcome};
     use fuel_core_types::fuel_types::canonical::Deserialize;
 
     use fuels::tx::Receipt;
     use fuels::types::bech32::{Bech32Address, FUEL_BECH32_HRP};
-    use fuels::types::{Bytes32, Identity, U256};
+    use fuels::types::{AssetId, Bytes32, Identity, U256};
     use fuels::{
         programs::calls::ContractDependency,
         types::{tx_status::TxStatus, Bits256},
     };
+    use proptest::prelude::*;
     use test_case::test_case;
 
     #[tokio::test]
@@ -594,6 +596,16 @@ mod success {
         let tx_status = provider.tx_status(&tx_id).await.unwrap();
         assert!(matches!(tx_status, TxStatus::Success { .. }));
 
+        let set_decimals: Vec<u8> =
+            BridgeEvent::from_receipts(bridge.contract_id().into(), &tx_status.take_receipts())
+                .into_iter()
+                .filter_map(|event| match event {
+                    BridgeEvent::SetDecimals(event) => Some(event.decimals),
+                    _ => None,
+                })
+                .collect();
+        assert_eq!(set_decimals, vec![expected_l2_decimals]);
+
         let l1_address: Bits256 = bridge
             .methods()
             .asset_to_l1_address(asset_id)
@@ -664,6 +676,24 @@ mod success {
             Identity::Address(Address::from_str(BRIDGED_TOKEN_GATEWAY).unwrap())
         );
 
+        // The typed decoder agrees with the ABI
+        let names_and_symbols: Vec<(AssetId, Option<String>)> =
+            BridgeEvent::from_receipts(bridge.contract_id().into(), &receipts)
+                .into_iter()
+                .filter_map(|event| match event {
+                    BridgeEvent::SetName(event) => Some((event.asset, event.name)),
+                    BridgeEvent::SetSymbol(event) => Some((event.asset, event.symbol)),
+                    _ => None,
+                })
+                .collect();
+        assert_eq!(
+            names_and_symbols,
+            vec![
+                (asset_id, Some(name.clone())),
+                (asset_id, Some(symbol.clone()))
+            ]
+        );
+
         let registered_name = bridge
             .methods()
             .name(asset_id)
@@ -772,6 +802,19 @@ mod success {
         assert_eq!(token_address, Bits256::from_hex_str(BRIDGED_TOKEN).unwrap());
         assert_eq!(from, Bits256::from_hex_str(FROM).unwrap());
         assert_eq!(token_id, Bits256::from_hex_str(BRIDGED_TOKEN_ID).unwrap());
+
+        // The typed decoder converts the amount to a U256
+        assert_eq!(
+            BridgeEvent::from_receipts(bridge.contract_id().into(), &receipts),
+            vec![BridgeEvent::RefundRegistered(
+                bridge_fungible_token_contract::events::RefundRegisteredEvent {
+                    amount: U256::from(deposit_amount),
+                    token_address,
+                    token_id,
+                    from,
+                }
+            )]
+        );
     }
 
     #[tokio::test]
@@ -895,6 +938,123 @@ mod success {
             token_two_registered_l1_address
         );
     }
+
+    // Deposits base layer amounts scaled down by the gateway one after the other,
+    // and checks the bridge mints or refunds each of them as predicted by
+    // `DecimalAdapter` for the total supply minted so far
+    async fn deposits_as_predicted(l1_decimals: u8, l2_amounts: &[u128]) {
+        let adapter = DecimalAdapter::new(l1_decimals);
+        let l1_amounts: Vec<U256> = l2_amounts
+            .iter()
+            .map(|l2_amount| adapter.to_l1_amount(U256::from(*l2_amount)).unwrap())
+            .collect();
+
+        let mut wallet = create_wallet();
+        let configurables: Option<BridgeFungibleTokenContractConfigurables> = None;
+
+        let (proxy_id, _implementation_contract_id) =
+            get_contract_ids(&wallet, configurables.clone());
+
+        let mut deposits = vec![];
+        for l1_amount in &l1_amounts {
+            let deposit = create_deposit_message(
+                BRIDGED_TOKEN,
+                BRIDGED_TOKEN_ID,
+                FROM,
+                *wallet.address().hash(),
+                adapter.to_l2_amount(*l1_amount).unwrap(),
+                l1_decimals.into(),
+                proxy_id,
+                false,
+                None,
+            )
+            .await;
+            deposits.push(deposit);
+        }
+        let coin = deposits[0].1;
+        let messages = deposits
+            .into_iter()
+            .map(|(message, _, _)| message)
+            .collect();
+
+        let (implementation_contract_id, bridge, utxo_inputs) =
+            setup_environment(&mut wallet, vec![coin], messages, None, None, configurables).await;
+
+        let asset_id = get_asset_id(bridge.contract_id(), BRIDGED_TOKEN);
+        let mut supply = 0u64;
+
+        for (message, l1_amount) in utxo_inputs.message.iter().zip(l1_amounts) {
+            let prediction = adapter.deposit(l1_amount, supply).unwrap();
+
+            let tx_id =
+                relay_message_to_contract(&wallet, message.clone(), utxo_inputs.contract.clone())
+                    .await;
+            let tx_status = wallet.provider().unwrap().tx_status(&tx_id).await.unwrap();
+            assert!(matches!(tx_status, TxStatus::Success { .. }));
+            let receipts = tx_status.take_receipts();
+
+            let refund_registered_events = bridge
+                .log_decoder()
+                .decode_logs_with_type::<RefundRegisteredEvent>(&receipts)
+                .unwrap();
+
+            match prediction {
+                DepositOutcome::Minted(amount) => {
+                    assert!(refund_registered_events.is_empty());
+                    supply += amount;
+
+                    let l2_decimals = bridge
+                        .methods()
+                        .decimals(asset_id)
+                        .with_contract_ids(&[implementation_contract_id.clone()])
+                        .call()
+                        .await
+                        .unwrap()
+                        .value;
+                    assert_eq!(l2_decimals, Some(adapter.l2_decimals()));
+                }
+                DepositOutcome::Refunded(amount) => {
+                    assert_eq!(refund_registered_events.len(), 1);
+                    assert_eq!(
+                        refund_registered_events[0].amount,
+                        Bits256(encode_hex(amount))
+                    );
+                }
+            }
+
+            let balance = wallet_balance(&wallet, &asset_id).await;
+            assert_eq!(balance, supply);
+        }
+    }
+
+    #[test_case(18, &[1_000]; "Minted")]
+    #[test_case(6, &[u128::from(u64::MAX)]; "Minted with fewer decimals")]
+    #[test_case(18, &[u128::from(u64::MAX) + 1]; "Refunded above u64 max")]
+    #[test_case(9, &[u128::from(u64::MAX), 1]; "Refunded above total supply")]
+    #[tokio::test]
+    async fn deposit_matches_decimal_adapter_at_limits(l1_decimals: u8, l2_amounts: &[u128]) {
+        deposits_as_predicted(l1_decimals, l2_amounts).await;
+    }
+
+    proptest! {
+        // Every case spins up a node
+        #![proptest_config(ProptestConfig::with_cases(8))]
+
+        #[test]
+        fn deposit_matches_decimal_adapter(
+            l1_decimals in 0u8..=24,
+            l2_amounts in prop::collection::vec(
+                prop_oneof![1..=u128::from(u64::MAX), u128::from(u64::MAX) + 1..],
+                1..=2,
+            ),
+        ) {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(deposits_as_predicted(l1_decimals, &l2_amounts));
+        }
+    }
 }
 
 mod revert {
@@ -1,4 +1,3 @@
-pub mod builder;
 pub mod constants;
 pub mod interface;
 pub mod setup;
@@ -1,15 +1,16 @@
-use crate::utils::{
-    builder,
-    constants::{
-        BRIDGED_TOKEN_DECIMALS, BRIDGE_FUNGIBLE_TOKEN_CONTRACT_BINARY,
-        CONTRACT_MESSAGE_PREDICATE_BINARY, DEPOSIT_RECIPIENT_CONTRACT_BINARY, MESSAGE_AMOUNT,
-        MESSAGE_SENDER_ADDRESS,
-    },
+use crate::utils::constants::{
+    BRIDGED_TOKEN_DECIMALS, BRIDGE_FUNGIBLE_TOKEN_CONTRACT_BINARY,
+    CONTRACT_MESSAGE_PREDICATE_BINARY, DEPOSIT_RECIPIENT_CONTRACT_BINARY, MESSAGE_AMOUNT,
+    MESSAGE_SENDER_ADDRESS,
+};
+use bridge_fungible_token_contract::{
+    asset::MAINNET_CHAIN_ID, bridged_asset_id, BridgeMessage, DepositMessage, DepositType,
+    MetadataMessage,
 };
-use ethers::abi::Token;
+use fuel_contract_message_predicate::RelayTxBuilder;
 use fuel_core_types::{
     fuel_crypto::SecretKey,
-    fuel_tx::{Bytes32, Output, TxId, TxPointer, UtxoId},
+    fuel_tx::{Bytes32, TxId, TxPointer, UtxoId},
     fuel_types::{Nonce, Word},
 };
 
@@ -22,13 +23,10 @@ use fuels::{
     test_helpers::{setup_single_message, DEFAULT_COIN_AMOUNT},
     types::{coin::Coin, input::Input, message::Message, tx_status::TxStatus, Bits256, U256},
 };
-use sha2::Digest;
-use std::{mem::size_of, num::ParseIntError, result::Result as StdResult, str::FromStr};
+use std::{mem::size_of, str::FromStr};
 
 use super::constants::{
-    BRIDGED_TOKEN, BRIDGED_TOKEN_ID, BRIDGE_PROXY_BINARY, DEPOSIT_TO_ADDRESS_FLAG,
-    DEPOSIT_TO_CONTRACT_FLAG, DEPOSIT_WITH_DATA_FLAG, FROM, METADATA_MESSAGE_FLAG,
-    REENTRANCY_ATTACKER_BINARY,
+    BRIDGED_TOKEN, BRIDGED_TOKEN_ID, BRIDGE_PROXY_BINARY, FROM, REENTRANCY_ATTACKER_BINARY,
 };
 
 abigen!(
@@ -209,24 +207,21 @@ pub(crate) async fn relay_message_to_contract(
 ) -> TxId {
     let provider = wallet.provider().expect("Wallet has no provider");
 
-    let gas_price: u64 = 1; // NodeInfo.min_gas_price is no longer available
-
-    let tx_policies = TxPolicies::new(Some(gas_price), None, Some(0), None, Some(300_000));
-
     let fetched_gas_coins: Vec<Coin> = provider
         .get_coins(wallet.address(), Default::default())
         .await
         .unwrap();
 
-    let tx = builder::build_contract_message_tx(
-        message,
-        contracts,
-        &fetched_gas_coins,
-        &[Output::variable(Address::zeroed(), 0, AssetId::default())],
-        tx_policies,
-        wallet,
-    )
-    .await;
+    let builder = RelayTxBuilder::new(message)
+        .with_contracts(contracts)
+        .with_gas_coins(&fetched_gas_coins);
+    let estimate = builder.estimate_fees(wallet, 20).await.unwrap();
+
+    let tx = builder
+        .with_tx_policies(estimate.apply(TxPolicies::default().with_maturity(0)))
+        .build(wallet)
+        .await
+        .unwrap();
 
     provider
         .send_transaction(tx)
@@ -258,15 +253,6 @@ pub(crate) async fn precalculate_reentrant_attacker_id(target: ContractId) -> Co
     compiled.contract_id()
 }
 
-/// Prefixes the given bytes with the test contract ID
-pub(crate) fn prefix_contract_id(mut data: Vec<u8>, contract_id: ContractId) -> Vec<u8> {
-    // Turn contract id into array with the given data appended to it
-    let test_contract_id: [u8; 32] = contract_id.into();
-    let mut test_contract_id = test_contract_id.to_vec();
-    test_contract_id.append(&mut data);
-    test_contract_id
-}
-
 pub(crate) async fn create_recipient_contract(
     wallet: WalletUnlocked,
 ) -> DepositRecipientContract<WalletUnlocked> {
@@ -302,15 +288,6 @@ pub(crate) async fn create_reentrancy_attacker_contract(
     ReentrancyAttacker::new(id, wallet)
 }
 
-/// Quickly converts the given hex string into a u8 vector
-pub(crate) fn decode_hex(s: &str) -> Vec<u8> {
-    let data: StdResult<Vec<u8>, ParseIntError> = (2..s.len())
-        .step_by(2)
-        .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-        .collect();
-    data.unwrap()
-}
-
 pub(crate) fn encode_hex(val: U256) -> [u8; 32] {
     let mut arr = [0u8; 32];
     val.to_big_endian(&mut arr);
@@ -329,34 +306,29 @@ pub(crate) async fn create_deposit_message(
     deposit_to_contract: bool,
     extra_data: Option<Vec<u8>>,
 ) -> ((u64, Vec<u8>), (u64, AssetId), Option<ContractId>) {
-    let mut message_data: Vec<u8> = vec![];
-
-    let deposit_type: u8 = match (deposit_to_contract, &extra_data) {
+    let deposit_type = match (deposit_to_contract, extra_data) {
         (false, Some(_)) => unreachable!(),
-        (false, None) => DEPOSIT_TO_ADDRESS_FLAG,
-        (true, None) => DEPOSIT_TO_CONTRACT_FLAG,
-        (true, Some(_)) => DEPOSIT_WITH_DATA_FLAG,
+        (false, None) => DepositType::Address,
+        (true, None) => DepositType::Contract,
+        (true, Some(data)) => DepositType::ContractWithData(data),
     };
 
-    message_data.append(&mut encode_hex(U256::from(deposit_type)).to_vec());
-    message_data.append(&mut decode_hex(token));
-    message_data.append(&mut decode_hex(token_id));
-    message_data.append(&mut decode_hex(from));
-    message_data.append(&mut to.to_vec());
-    message_data.append(&mut encode_hex(amount).to_vec());
-    message_data.append(&mut encode_hex(U256::from(decimals)).to_vec());
-
     let mut deposit_recipient: Option<ContractId> = None;
 
     if deposit_to_contract {
         deposit_recipient = Option::Some(ContractId::new(to));
     };
 
-    if let Some(mut data) = extra_data {
-        message_data.append(&mut data);
-    };
-
-    let message_data = prefix_contract_id(message_data, message_recipient);
+    let message_data = BridgeMessage::Deposit(DepositMessage {
+        token_address: Bits256::from_hex_str(token).unwrap(),
+        token_id: Bits256::from_hex_str(token_id).unwrap(),
+        from: Bits256::from_hex_str(from).unwrap(),
+        to: Bits256(to),
+        amount,
+        decimals: decimals.try_into().unwrap(),
+        deposit_type,
+    })
+    .encode(message_recipient);
     let message = (MESSAGE_AMOUNT, message_data);
     let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
 
@@ -370,36 +342,13 @@ pub(crate) async fn create_metadata_message(
     token_symbol: &str,
     contract_recipient: ContractId,
 ) -> Vec<u8> {
-    let mut message_data: Vec<u8> = vec![];
-    message_data.append(&mut encode_hex(U256::from(METADATA_MESSAGE_FLAG)).to_vec());
-
-    let items: Vec<Token> = vec![
-        Token::FixedBytes(decode_hex(token_address)),
-        Token::FixedBytes(decode_hex(token_id)),
-        Token::String(String::from(token_name)),
-        Token::String(String::from(token_symbol)),
-    ];
-    let mut payload = ethers::abi::encode(&items);
-    message_data.append(&mut payload);
-
-    prefix_contract_id(message_data, contract_recipient)
-}
-
-pub(crate) fn parse_output_message_data(data: &[u8]) -> (Vec<u8>, Bits256, Bits256, U256, Bits256) {
-    let selector = &data[0..4];
-    let to: [u8; 32] = data[4..36].try_into().unwrap();
-    let token_array: [u8; 32] = data[36..68].try_into().unwrap();
-    let token = Bits256(token_array);
-    let amount_array: [u8; 32] = data[68..100].try_into().unwrap();
-    let amount: U256 = U256::from_big_endian(amount_array.as_ref());
-    let token_id: [u8; 32] = data[100..132].try_into().unwrap();
-    (
-        selector.to_vec(),
-        Bits256(to),
-        token,
-        amount,
-        Bits256(token_id),
-    )
+    BridgeMessage::Metadata(MetadataMessage {
+        token_address: Bits256::from_hex_str(token_address).unwrap(),
+        token_id: Bits256::from_hex_str(token_id).unwrap(),
+        name: String::from(token_name),
+        symbol: String::from(token_symbol),
+    })
+    .encode(contract_recipient)
 }
 
 pub(crate) async fn contract_balance(
@@ -418,16 +367,12 @@ pub(crate) async fn wallet_balance(wallet: &WalletUnlocked, asset_id: &AssetId)
 }
 
 pub(crate) fn get_asset_id(contract_id: &Bech32ContractId, token: &str) -> AssetId {
-    let chain_id = "1".as_bytes();
-    let data: Vec<u8> = chain_id
-        .iter()
-        .chain(Bits256::from_hex_str(token).unwrap().0.iter())
-        .chain(Bits256::zeroed().0.iter())
-        .cloned()
-        .collect();
-
-    let sub_id = sha2::Sha256::digest(data);
-    contract_id.asset_id(&Bits256::from_hex_str(&hex::encode(sub_id)).unwrap())
+    bridged_asset_id(
+        contract_id.into(),
+        MAINNET_CHAIN_ID,
+        Bits256::from_hex_str(token).unwrap(),
+        Bits256::zeroed(),
+    )
 }
 
 /// This setup mints tokens so that they are registered as minted assets in the bridge
@@ -0,0 +1,98 @@
+// Measures the gas used to evaluate the contract message predicate and its
+// gas-optimized version, for relay transactions with 1, 10 and 100 inputs besides
+// the relayed message, along with the minimum gas of the whole transaction, which
+// includes the predicate gas and the gas paid for the transaction size
+use fuel_contract_message_predicate::{
+    evaluate::evaluate_predicates, predicate_bytecode, predicate_v2_bytecode, script_bytecode,
+};
+use fuel_tx::{
+    field::Inputs, Address, AssetId, Bytes32, Chargeable, ConsensusParameters, Input, Output,
+    Script, TransactionBuilder, TxPointer, UtxoId, Witness,
+};
+use fuel_vm::{
+    checked_transaction::{CheckPredicateParams, EstimatePredicates},
+    fuel_types::Nonce,
+    interpreter::MemoryInstance,
+};
+
+const INPUT_COUNTS: [usize; 3] = [1, 10, 100];
+// Target contract id followed by a payload, as read by the receiver
+const MESSAGE_DATA: [u8; 72] = [1; 72];
+
+// Relays a message with data owned by the predicate at input 0, followed by
+// `inputs` signed coins paying for the transaction, so that only one predicate is
+// evaluated. The predicates check every input other than a message the same way
+fn relay_tx(predicate: Vec<u8>, inputs: usize) -> Script {
+    let mut builder = TransactionBuilder::script(script_bytecode(), vec![]);
+    builder.script_gas_limit(1_000_000);
+    builder.add_witness(Witness::default());
+
+    builder.add_input(Input::message_data_predicate(
+        Address::zeroed(),
+        Input::predicate_owner(&predicate),
+        0,
+        Nonce::zeroed(),
+        0,
+        MESSAGE_DATA.to_vec(),
+        predicate,
+        vec![],
+    ));
+    for index in 0..inputs {
+        builder.add_input(Input::coin_signed(
+            UtxoId::new(Bytes32::zeroed(), index as u16),
+            Address::zeroed(),
+            1_000,
+            AssetId::BASE,
+            TxPointer::default(),
+            0,
+        ));
+    }
+    builder.add_output(Output::change(Address::zeroed(), 0, AssetId::BASE));
+
+    builder.finalize_without_signature_inner()
+}
+
+// Gets the predicate gas and the minimum gas of the transaction
+fn measure(predicate: &[u8], inputs: usize, params: &ConsensusParameters) -> (u64, u64) {
+    let mut tx = relay_tx(predicate.to_vec(), inputs);
+    let gas_used = evaluate_predicates(&tx, params).expect("The predicate accepts the relay");
+
+    tx.estimate_predicates(&CheckPredicateParams::from(params), MemoryInstance::new())
+        .expect("The predicate gas can be estimated");
+    assert_eq!(tx.inputs()[0].predicate_gas_used(), Some(gas_used));
+
+    (
+        gas_used,
+        tx.min_gas(params.gas_costs(), params.fee_params()),
+    )
+}
+
+fn main() {
+    let params = ConsensusParameters::standard();
+    let predicates = [
+        ("v1", predicate_bytecode()),
+        ("v2", predicate_v2_bytecode()),
+    ];
+
+    println!(
+        "{:>8} {:>10} {:>10} {:>10} {:>10}",
+        "inputs", "v1", "v2", "v1 total", "v2 total"
+    );
+    for inputs in INPUT_COUNTS {
+        let gas: Vec<(u64, u64)> = predicates
+            .iter()
+            .map(|(_, predicate)| measure(predicate, inputs, &params))
+            .collect();
+
+        println!(
+            "{:>8} {:>10} {:>10} {:>10} {:>10}",
+            inputs, gas[0].0, gas[1].0, gas[0].1, gas[1].1,
+        );
+    }
+
+    println!(
+        "bytecode {:>10} {:>10}",
+        predicates[0].1.len(),
+        predicates[1].1.len()
+    );
+}
@@ -1,29 +1,19 @@
 use fuel_tx::Input;
-use sha2::{Digest, Sha256};
 use std::{env, fs, path::Path};
 
 const SCRIPT_BUILD_PATH: &str = "contract_message_script.bin";
 const SCRIPT_HASH_PATH: &str = "contract_message_script_hash.bin";
 const PREDICATE_BUILD_PATH: &str = "contract_message_predicate.bin";
+const PREDICATE_V2_BUILD_PATH: &str = "contract_message_predicate_v2.bin";
 // The precomputed predicate root for chain_id=0
 const DEFAULT_PREDICATE_ROOT_PATH: &str = "contract_message_predicate_default_root.bin";
 
-mod predicate_asm;
-mod script_asm;
+// The generators are shared with the library, see `src/codegen`
+#[allow(dead_code)]
+#[path = "src/codegen/mod.rs"]
+mod codegen;
 
-pub fn script_hash() -> [u8; 32] {
-    let script = script_asm::bytecode();
-    let mut script_hasher = Sha256::new();
-    script_hasher.update(script);
-    script_hasher.finalize().into()
-}
-
-// Gets the root of the message-to-contract predicate
-pub fn predicate_root() -> [u8; 32] {
-    let predicate = predicate_asm::bytecode();
-    let root = Input::predicate_owner(predicate);
-    root.into()
-}
+use codegen::{predicate_asm, script_asm};
 
 fn main() {
     let out_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
@@ -31,14 +21,16 @@ fn main() {
     // get predicate and script bytecode
     let script = script_asm::bytecode();
     let predicate = predicate_asm::bytecode();
+    let predicate_v2 = predicate_asm::bytecode_v2();
 
     // output to console and build files
-    let script_hash = script_hash();
-    let predicate_root = predicate_root();
+    let script_hash = codegen::script_hash(&script);
+    let predicate_root: [u8; 32] = Input::predicate_owner(&predicate).into();
 
     let script_build_path = out_dir.join(SCRIPT_BUILD_PATH);
     let script_hash_path = out_dir.join(SCRIPT_HASH_PATH);
     let predicate_build_path = out_dir.join(PREDICATE_BUILD_PATH);
+    let predicate_v2_build_path = out_dir.join(PREDICATE_V2_BUILD_PATH);
     let default_predicate_root_path = out_dir.join(DEFAULT_PREDICATE_ROOT_PATH);
 
     fs::create_dir_all(out_dir.clone())
@@ -52,6 +44,10 @@ fn main() {
         panic!("Failed to write to predicate binary file output [{predicate_build_path:?}].")
     });
 
+    fs::write(predicate_v2_build_path.clone(), predicate_v2).unwrap_or_else(|_| {
+        panic!("Failed to write to predicate v2 binary file output [{predicate_v2_build_path:?}].")
+    });
+
     fs::write(script_hash_path.clone(), script_hash).unwrap_or_else(|_| {
         panic!("Failed to write to script hash file output [{script_hash_path:?}].")
     });
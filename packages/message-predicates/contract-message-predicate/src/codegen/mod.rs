@@ -0,0 +1,14 @@
+// Runtime generators for the message-to-contract script and predicate.
+// build.rs uses these same generators to emit the cached artifacts in `out/`
+
+use sha2::{Digest, Sha256};
+
+pub mod predicate_asm;
+pub mod script_asm;
+
+// Gets the hash of the given script, as checked by the predicate
+pub fn script_hash(script: &[u8]) -> [u8; 32] {
+    let mut script_hasher = Sha256::new();
+    script_hasher.update(script);
+    script_hasher.finalize().into()
+}
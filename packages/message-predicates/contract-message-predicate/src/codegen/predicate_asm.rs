@@ -0,0 +1,332 @@
+use fuel_asm::{op, GTFArgs, RegId};
+
+const INPUT_MESSAGE_TYPE: u32 = 2;
+const BYTES_PER_INSTR: u16 = 4;
+
+// Gets the bytecode for the message-to-contract predicate
+pub fn bytecode() -> Vec<u8> {
+    bytecode_for_script_hash(super::script_hash(&super::script_asm::bytecode()))
+}
+
+// Gets the bytecode for a message-to-contract predicate that only accepts the
+// script with the given hash
+pub fn bytecode_for_script_hash(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_HASH_PTR: u8 = 0x10;
+    const REG_SCRIPT_PTR: u8 = 0x11;
+    const REG_SCRIPT_LEN: u8 = 0x12;
+    const REG_EXPECTED_HASH_PTR: u8 = 0x13;
+    const REG_RESULT: u8 = 0x14;
+    const REG_VAL_32: u8 = 0x16;
+    const REG_INPUT_INDEX: u8 = 0x17;
+    const REG_INPUT_TYPE: u8 = 0x18;
+    const REG_INPUT_MSG_DATA_LEN: u8 = 0x19;
+    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1a;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 11;
+    const JMP_SKIP_DATA_CHECK: u16 = 16;
+    const JMP_PREDICATE_FAILURE: u16 = 18;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 19 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = vec![
+        //extend stack for storing script hash
+        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+        op::cfei(32),                       //extends current call frame stack by 32 bytes
+        //compute script hash
+        op::gtf(REG_SCRIPT_PTR, RegId::ZERO, GTFArgs::Script.into()), //REG_SCRIPT_PTR = script data address
+        op::gtf(REG_SCRIPT_LEN, RegId::ZERO, GTFArgs::ScriptLength.into()), //REG_SCRIPT_LEN = script data length
+        op::s256(REG_HASH_PTR, REG_SCRIPT_PTR, REG_SCRIPT_LEN), //32bytes at SCRIPT_HASH_PTR = hash of the script
+        //compare hash with expected
+        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
+        op::movi(REG_VAL_32, 32),                                       //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+        //confirm that no other messages with data are included
+        op::gtf(
+            REG_INPUT_INDEX,
+            RegId::ZERO,
+            GTFArgs::ScriptInputsCount.into(),
+        ), //REG_INPUT_INDEX = the number of inputs in the script
+        op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
+        //LOOP_START:
+        op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
+        //check if the input is a message input
+        op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+        op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_SKIP_DATA_CHECK), //skips to SKIP_DATA_CHECK if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+        //check if the input message has data
+        op::gtf(
+            REG_INPUT_MSG_DATA_LEN,
+            REG_INPUT_INDEX,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+        op::jnei(REG_INPUT_MSG_DATA_LEN, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_INPUT_MSG_DATA_LEN does not equal 0
+        //SKIP_DATA_CHECK:
+        op::jnei(REG_INPUT_INDEX, RegId::ONE, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal 1
+        op::ret(RegId::ONE),
+        //PREDICATE_FAILURE:
+        op::ret(RegId::ZERO),
+        //referenced data (expected script hash)
+        //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+    ]
+    .into_iter()
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for the gas-optimized message-to-contract predicate
+pub fn bytecode_v2() -> Vec<u8> {
+    bytecode_v2_for_script_hash(super::script_hash(&super::script_asm::bytecode()))
+}
+
+// Gets the bytecode for a gas-optimized message-to-contract predicate that only
+// accepts the script with the given hash. It has the size of the predicate built
+// by `bytecode_for_script_hash`, but walks the inputs down to input 0 so that
+// non-message inputs cost one instruction less, and folds the script hash check
+// into the return value instead of branching on it. Walking down to input 0 also
+// requires the first input to be a message, which the relay script reads anyway
+pub fn bytecode_v2_for_script_hash(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_HASH_PTR: u8 = 0x10;
+    const REG_SCRIPT_PTR: u8 = 0x11;
+    const REG_SCRIPT_LEN: u8 = 0x12;
+    const REG_EXPECTED_HASH_PTR: u8 = 0x13;
+    const REG_RESULT: u8 = 0x14;
+    const REG_VAL_32: u8 = 0x16;
+    const REG_INPUT_INDEX: u8 = 0x17;
+    const REG_INPUT_TYPE: u8 = 0x18;
+    const REG_INPUT_MSG_DATA_LEN: u8 = 0x19;
+    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1a;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 10;
+    const JMP_DATA_CHECK: u32 = 15;
+    const JMP_PREDICATE_FAILURE: u32 = 18;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 19 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there are no other `InputMessages` with data in the transaction
+     *   other than the first input, which must be a message
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = vec![
+        //extend stack for storing script hash
+        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+        op::cfei(32),                       //extends current call frame stack by 32 bytes
+        //compute script hash
+        op::gtf(REG_SCRIPT_PTR, RegId::ZERO, GTFArgs::Script.into()), //REG_SCRIPT_PTR = script data address
+        op::gtf(REG_SCRIPT_LEN, RegId::ZERO, GTFArgs::ScriptLength.into()), //REG_SCRIPT_LEN = script data length
+        op::s256(REG_HASH_PTR, REG_SCRIPT_PTR, REG_SCRIPT_LEN), //32bytes at SCRIPT_HASH_PTR = hash of the script
+        //compare hash with expected, the result is returned once the inputs are checked
+        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
+        op::movi(REG_VAL_32, 32),                                       //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+        //confirm that no other messages with data are included
+        op::gtf(
+            REG_INPUT_INDEX,
+            RegId::ZERO,
+            GTFArgs::ScriptInputsCount.into(),
+        ), //REG_INPUT_INDEX = the number of inputs in the script
+        op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
+        //LOOP_START:
+        op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1, panics below input 0
+        //check if the input is a message input
+        op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+        op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+        op::jnzi(REG_INPUT_INDEX, JMP_DATA_CHECK), //jumps to DATA_CHECK if REG_INPUT_INDEX does not equal 0
+        op::ret(REG_RESULT),                       //returns 1 if the script hash matched
+        //DATA_CHECK:
+        op::gtf(
+            REG_INPUT_MSG_DATA_LEN,
+            REG_INPUT_INDEX,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+        op::jnzi(REG_INPUT_MSG_DATA_LEN, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_INPUT_MSG_DATA_LEN does not equal 0
+        op::ji(JMP_LOOP_START.into()),                           //jumps back to LOOP_START
+        //PREDICATE_FAILURE:
+        op::ret(RegId::ZERO),
+        //referenced data (expected script hash)
+        //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+    ]
+    .into_iter()
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for the predicate paired with the any-input script
+pub fn any_input_bytecode() -> Vec<u8> {
+    any_input_bytecode_for_script_hash(super::script_hash(&super::script_asm::any_input_bytecode()))
+}
+
+// Gets the bytecode for a predicate that only accepts the script with the given
+// hash and at most one `InputMessage` with data, placed at any input index
+pub fn any_input_bytecode_for_script_hash(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_HASH_PTR: u8 = 0x10;
+    const REG_SCRIPT_PTR: u8 = 0x11;
+    const REG_SCRIPT_LEN: u8 = 0x12;
+    const REG_EXPECTED_HASH_PTR: u8 = 0x13;
+    const REG_RESULT: u8 = 0x14;
+    const REG_DATA_MSG_FOUND: u8 = 0x15;
+    const REG_VAL_32: u8 = 0x16;
+    const REG_INPUT_INDEX: u8 = 0x17;
+    const REG_INPUT_TYPE: u8 = 0x18;
+    const REG_INPUT_MSG_DATA_LEN: u8 = 0x19;
+    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1a;
+    const REG_NO_DATA: u8 = 0x1b;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 12;
+    const JMP_SKIP_DATA_CHECK: u16 = 20;
+    const JMP_PREDICATE_FAILURE: u16 = 22;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 23 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected Message to Contract script
+     *  -Verify there is at most one `InputMessage` with data in the transaction,
+     *   regardless of its position in the inputs
+     *
+     * If these conditions are met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = vec![
+        //extend stack for storing script hash
+        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+        op::cfei(32),                       //extends current call frame stack by 32 bytes
+        //compute script hash
+        op::gtf(REG_SCRIPT_PTR, RegId::ZERO, GTFArgs::Script.into()), //REG_SCRIPT_PTR = script data address
+        op::gtf(REG_SCRIPT_LEN, RegId::ZERO, GTFArgs::ScriptLength.into()), //REG_SCRIPT_LEN = script data length
+        op::s256(REG_HASH_PTR, REG_SCRIPT_PTR, REG_SCRIPT_LEN), //32bytes at SCRIPT_HASH_PTR = hash of the script
+        //compare hash with expected
+        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
+        op::movi(REG_VAL_32, 32),                                       //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+        op::jnei(REG_RESULT, RegId::ONE, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if REG_RESULT is not 1
+        //confirm that at most one message with data is included
+        op::gtf(
+            REG_INPUT_INDEX,
+            RegId::ZERO,
+            GTFArgs::ScriptInputsCount.into(),
+        ), //REG_INPUT_INDEX = the number of inputs in the script
+        op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = REG_INPUT_MESSAGE_TYPE
+        op::move_(REG_DATA_MSG_FOUND, RegId::ZERO),            //REG_DATA_MSG_FOUND = 0
+        //LOOP_START:
+        op::subi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX - 1
+        //check if the input is a message input
+        op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+        op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_SKIP_DATA_CHECK), //skips to SKIP_DATA_CHECK if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+        //check if the input message has data
+        op::gtf(
+            REG_INPUT_MSG_DATA_LEN,
+            REG_INPUT_INDEX,
+            GTFArgs::InputMessageDataLength.into(),
+        ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+        op::eq(REG_NO_DATA, REG_INPUT_MSG_DATA_LEN, RegId::ZERO), //REG_NO_DATA = if REG_INPUT_MSG_DATA_LEN equals 0
+        op::jnei(REG_NO_DATA, RegId::ZERO, JMP_SKIP_DATA_CHECK), //skips to SKIP_DATA_CHECK if the input message has no data
+        op::jnei(REG_DATA_MSG_FOUND, RegId::ZERO, JMP_PREDICATE_FAILURE), //jumps to PREDICATE_FAILURE if a message with data was already found
+        op::movi(REG_DATA_MSG_FOUND, 1),                                  //REG_DATA_MSG_FOUND = 1
+        //SKIP_DATA_CHECK:
+        op::jnei(REG_INPUT_INDEX, RegId::ZERO, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal 0
+        op::ret(RegId::ONE),
+        //PREDICATE_FAILURE:
+        op::ret(RegId::ZERO),
+        //referenced data (expected script hash)
+        //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+    ]
+    .into_iter()
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
+
+// Gets the bytecode for the predicate paired with the value-forwarding script
+pub fn value_forwarding_bytecode() -> Vec<u8> {
+    bytecode_for_script_hash(super::script_hash(
+        &super::script_asm::value_forwarding_bytecode(),
+    ))
+}
+
+// Gets the bytecode for the predicate paired with the reporting script
+pub fn reporting_bytecode() -> Vec<u8> {
+    bytecode_for_script_hash(super::script_hash(&super::script_asm::reporting_bytecode()))
+}
+
+// Gets the bytecode for the predicate paired with the fee-capped script
+pub fn fee_capped_bytecode() -> Vec<u8> {
+    bytecode_for_script_hash(super::script_hash(&super::script_asm::fee_capped_bytecode()))
+}
+
+// Gets the bytecode for the predicate paired with the batch script
+pub fn batch_bytecode() -> Vec<u8> {
+    script_only_bytecode_for_script_hash(super::script_hash(&super::script_asm::batch_bytecode()))
+}
+
+// Gets the bytecode for a predicate that only accepts the script with the given
+// hash, without restricting the other inputs. Only suitable for scripts that
+// process every `InputMessage` with data, such as the batch script
+pub fn script_only_bytecode_for_script_hash(script_hash: [u8; 32]) -> Vec<u8> {
+    //register names
+    const REG_HASH_PTR: u8 = 0x10;
+    const REG_SCRIPT_PTR: u8 = 0x11;
+    const REG_SCRIPT_LEN: u8 = 0x12;
+    const REG_EXPECTED_HASH_PTR: u8 = 0x13;
+    const REG_RESULT: u8 = 0x14;
+    const REG_VAL_32: u8 = 0x16;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 9 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to do the following:
+     *  -Verify that the script bytecode hash for the transaction matches that of
+     *   the expected script
+     *
+     * If this condition is met, then the predicate evaluates as true.
+     */
+    let mut predicate: Vec<u8> = vec![
+        //extend stack for storing script hash
+        op::move_(REG_HASH_PTR, RegId::SP), //REG_HASH_PTR = stack pointer
+        op::cfei(32),                       //extends current call frame stack by 32 bytes
+        //compute script hash
+        op::gtf(REG_SCRIPT_PTR, RegId::ZERO, GTFArgs::Script.into()), //REG_SCRIPT_PTR = script data address
+        op::gtf(REG_SCRIPT_LEN, RegId::ZERO, GTFArgs::ScriptLength.into()), //REG_SCRIPT_LEN = script data length
+        op::s256(REG_HASH_PTR, REG_SCRIPT_PTR, REG_SCRIPT_LEN), //32bytes at SCRIPT_HASH_PTR = hash of the script
+        //compare hash with expected
+        op::addi(REG_EXPECTED_HASH_PTR, RegId::IS, REF_DATA_START_PTR), //REG_EXPECTED_HASH_PTR = address of reference data at end of program
+        op::movi(REG_VAL_32, 32),                                       //REG_VAL_32 = 32
+        op::meq(REG_RESULT, REG_EXPECTED_HASH_PTR, REG_HASH_PTR, REG_VAL_32), //REG_RESULT = if the 32bytes at REG_HASH_PTR equals the 32bytes at REG_EXPECTED_HASH_PTR
+        op::ret(REG_RESULT), //returns 1 only if the script hash matches
+                             //referenced data (expected script hash)
+                             //00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000
+    ]
+    .into_iter()
+    .collect();
+
+    //add referenced data (expected script hash)
+    predicate.append(&mut script_hash.to_vec());
+    predicate
+}
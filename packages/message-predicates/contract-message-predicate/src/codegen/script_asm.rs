@@ -0,0 +1,404 @@
+use fuel_asm::{op, GMArgs, GTFArgs, Instruction, RegId};
+
+const PROCESS_MESSAGE_FUNCTION_SIGNATURE: &str = "process_message";
+const INPUT_MESSAGE_TYPE: u32 = 2;
+const BYTES_PER_INSTR: u16 = 4;
+
+/// Id of the `LogData` receipt logging the target contract of the reporting script
+pub const RELAY_TARGET_LOG_ID: u32 = 0x3f001;
+/// Value returned by the reporting script once the message was delivered
+pub const RELAY_DELIVERED: u32 = 0x3f002;
+/// Length of the relayer fee read by the fee-capped script from the last bytes of
+/// the message data, after the payload of the receiver
+pub const RELAYER_FEE_LEN: usize = 8;
+
+//register names shared by all the scripts, variants use the registers from 0x17
+const REG_MEMORY_START_PTR: u8 = 0x10;
+const REG_DATA_PTR: u8 = 0x11;
+const REG_CONTRACT_ADDR_PTR: u8 = 0x12;
+const REG_FN_SELECTOR_PTR: u8 = 0x13;
+const REG_DATA_FN_SELECTOR_PTR: u8 = 0x14;
+const REG_DATA_CALLDATA_PTR: u8 = 0x15;
+const REG_CALLDATA_PTR: u8 = 0x16;
+
+//number of instructions of `bytecode_with_call` before the call instructions
+const CALL_SETUP_LEN: u16 = 11;
+
+// Gets the bytecode for the message-to-contract script
+pub fn bytecode() -> Vec<u8> {
+    // process_message(msg_idx = 0)
+    bytecode_with_selector(PROCESS_MESSAGE_FUNCTION_SIGNATURE, &0u64.to_be_bytes())
+}
+
+// Gets the bytecode for a message-to-contract script that calls `fn_name` with
+// the already encoded `args` on the contract designated by the message data
+pub fn bytecode_with_selector(fn_name: &str, args: &[u8]) -> Vec<u8> {
+    /* The following assembly code is intended to:
+     * Call the function `fn_name` on the contract with ID that matches
+     * the first 32 bytes in the message data field. It won't forward the possible value
+     * stored in the message. L1 entities sending messages here MUST NOT attach
+     * a base asset amount, or it will be permanently lost.
+     */
+    bytecode_with_call(
+        fn_name,
+        args,
+        vec![
+            op::call(REG_DATA_PTR, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ZERO),
+        ],
+    )
+}
+
+// Gets the bytecode for a message-to-contract script that sets up the call of
+// `fn_name` with the already encoded `args` on the contract designated by the
+// data of the message at input[0], then runs `call_instructions`. These make
+// the call with the parameters at REG_DATA_PTR, can use the registers from 0x17,
+// and must end the script. Absolute jumps among them are offset by CALL_SETUP_LEN
+fn bytecode_with_call(fn_name: &str, args: &[u8], call_instructions: Vec<Instruction>) -> Vec<u8> {
+    let mut fn_selector_bytes = fuels::core::codec::encode_fn_selector(fn_name);
+    // new encoding: len of the function signature as u64 (8 bytes) + bytes of the function signature
+    let fn_sel_bytes_len: u16 = (8 + fn_name.len())
+        .try_into()
+        .expect("Function name is too long");
+    assert_eq!(fn_selector_bytes.len() as u16, fn_sel_bytes_len);
+
+    //referenced data start pointer
+    let ref_data_start_ptr = (CALL_SETUP_LEN + call_instructions.len() as u16) * BYTES_PER_INSTR;
+
+    // Both pointers into the referenced data must fit in a 12 bit immediate
+    let calldata_offset = ref_data_start_ptr + fn_sel_bytes_len;
+    assert!(
+        calldata_offset < 1 << 12,
+        "Function name is too long to be referenced by the script"
+    );
+
+    let mut script: Vec<u8> = call_frame(0)
+        .into_iter()
+        .chain(contract_id_param(RegId::ZERO))
+        .chain(fn_selector_param(ref_data_start_ptr))
+        .chain([
+            op::addi(REG_CALLDATA_PTR, RegId::IS, calldata_offset), // REG_CALLDATA_PTR = (End of IS) + len of the function selector
+        ])
+        .chain(calldata_param())
+        .chain(call_instructions)
+        .collect();
+
+    // At the tail of the script, after the return statement, embed:
+    script.append(&mut fn_selector_bytes); // function selector
+    script.extend_from_slice(args); // encoded arguments, e.g. 8 bytes of msg_idx
+
+    script
+}
+
+// Reserves the stack for the call: the base asset id, followed by the call
+// parameters [contract id, param1, param2] at REG_DATA_PTR and `extra_len` bytes
+fn call_frame(extra_len: u32) -> [Instruction; 3] {
+    [
+        op::move_(REG_MEMORY_START_PTR, RegId::SP), //REG_MEMORY_START_PTR = stack pointer
+        op::cfei(32 + 32 + 8 + 8 + extra_len), //extends current call frame stack by 32+32+8+8 bytes [base asset id, contract id, param1, param2] + extra_len
+        op::addi(REG_DATA_PTR, REG_MEMORY_START_PTR, 32), //REG_DATA_PTR = REG_MEMORY_START_PTR + 32bytes [memory start pointer + 32]
+    ]
+}
+
+// Copies the contract id at the start of the data of the message at input[reg_input_index]
+// into the call parameters
+fn contract_id_param(reg_input_index: RegId) -> [Instruction; 2] {
+    [
+        op::gtf(
+            REG_CONTRACT_ADDR_PTR,
+            reg_input_index,
+            GTFArgs::InputMessageData.into(),
+        ), //REG_CONTRACT_ADDR_PTR = memory location of the message data from input[reg_input_index]
+        op::mcpi(REG_DATA_PTR, REG_CONTRACT_ADDR_PTR, 32), // REG_DATA[0..31] = REG_CONTRACT_ADDR_PTR[0..31]
+    ]
+}
+
+// Points param1 of the call at the function selector embedded at `ref_data_start_ptr`
+fn fn_selector_param(ref_data_start_ptr: u16) -> [Instruction; 3] {
+    [
+        op::addi(REG_FN_SELECTOR_PTR, RegId::IS, ref_data_start_ptr),
+        op::addi(REG_DATA_FN_SELECTOR_PTR, REG_DATA_PTR, 32), // REG_DATA_FN_SELECTOR_PTR = REG_DATA_PTR + 32
+        op::sw(REG_DATA_FN_SELECTOR_PTR, REG_FN_SELECTOR_PTR, 0), // REG_DATA[32..39] = (End of IS)[0..7] = pointer to the function selector
+    ]
+}
+
+// Points param2 of the call at the encoded arguments at REG_CALLDATA_PTR
+fn calldata_param() -> [Instruction; 2] {
+    [
+        op::addi(REG_DATA_CALLDATA_PTR, REG_DATA_PTR, 40), // REG_DATA_CALLDATA_PTR = REG_DATA_PTR + 40
+        op::sw(REG_DATA_CALLDATA_PTR, REG_CALLDATA_PTR, 0), // REG_DATA[40..47] = pointer to the encoded arguments
+    ]
+}
+
+// Gets the bytecode for a message-to-contract script that looks for the
+// `InputMessage` with data among all the inputs, instead of expecting it at
+// input[0], and calls `process_message(msg_idx)` with the index it was found at
+pub fn any_input_bytecode() -> Vec<u8> {
+    let mut fn_selector_bytes =
+        fuels::core::codec::encode_fn_selector(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_INPUTS_COUNT: u8 = 0x17;
+    const REG_INPUT_INDEX: u8 = 0x18;
+    const REG_INPUT_TYPE: u8 = 0x19;
+    const REG_INPUT_MSG_DATA_LEN: u8 = 0x1a;
+    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1b;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 6;
+    const JMP_NEXT_INPUT: u16 = 10;
+    const JMP_MESSAGE_FOUND: u16 = 12;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 23 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to:
+     * Find the first `InputMessage` with data, then call the function `process_message`
+     * with its input index on the contract with ID that matches the first 32 bytes in
+     * the message data field. If no such input exists, reading its data panics with
+     * `InputNotFound`. Like the default script, it won't forward the possible value
+     * stored in the message.
+     */
+    let mut script: Vec<u8> = call_frame(8) // [msg_idx]
+        .into_iter()
+        .chain([
+            op::gtf(
+                REG_INPUTS_COUNT,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUTS_COUNT = the number of inputs in the script
+            op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = INPUT_MESSAGE_TYPE
+            op::move_(REG_INPUT_INDEX, RegId::ZERO),               //REG_INPUT_INDEX = 0
+            //LOOP_START:
+            op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+            op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_NEXT_INPUT), //skips to NEXT_INPUT if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                REG_INPUT_INDEX,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+            op::jnei(REG_INPUT_MSG_DATA_LEN, RegId::ZERO, JMP_MESSAGE_FOUND), //jumps to MESSAGE_FOUND if REG_INPUT_MSG_DATA_LEN does not equal 0
+            //NEXT_INPUT:
+            op::addi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX + 1
+            op::jnei(REG_INPUT_INDEX, REG_INPUTS_COUNT, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal REG_INPUTS_COUNT
+        ])
+        //MESSAGE_FOUND:
+        .chain(contract_id_param(RegId::new(REG_INPUT_INDEX)))
+        .chain(fn_selector_param(REF_DATA_START_PTR))
+        .chain([
+            op::addi(REG_CALLDATA_PTR, REG_DATA_PTR, 48), // REG_CALLDATA_PTR = REG_DATA_PTR + 48
+            op::sw(REG_CALLDATA_PTR, REG_INPUT_INDEX, 0), // REG_DATA[48..55] = msg_idx = REG_INPUT_INDEX
+        ])
+        .chain(calldata_param())
+        .chain([
+            op::call(REG_DATA_PTR, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ZERO),
+        ])
+        .collect();
+
+    // At the tail of the script, after the return statement, embed:
+    script.append(&mut fn_selector_bytes); // function selector
+
+    script
+}
+
+// Gets the bytecode for a message-to-contract script that forwards the message
+// amount of base asset to the contract while calling `process_message(msg_idx = 0)`
+pub fn value_forwarding_bytecode() -> Vec<u8> {
+    //register names
+    const REG_MSG_AMOUNT: u8 = 0x17;
+    const REG_BASE_ASSET_PTR: u8 = 0x18;
+
+    /* The following assembly code is intended to:
+     * Call the function `process_message` on the contract with ID that matches
+     * the first 32 bytes in the message data field, forwarding the exact amount
+     * of base asset specified in the message `amount` field.
+     */
+    bytecode_with_call(
+        PROCESS_MESSAGE_FUNCTION_SIGNATURE,
+        &0u64.to_be_bytes(),
+        vec![
+            op::gtf(
+                REG_MSG_AMOUNT,
+                RegId::ZERO,
+                GTFArgs::InputMessageAmount.into(),
+            ), //REG_MSG_AMOUNT = amount of the message from input[0]
+            op::gm_args(REG_BASE_ASSET_PTR, GMArgs::BaseAssetId), //REG_BASE_ASSET_PTR = memory location of the base asset id
+            op::call(
+                REG_DATA_PTR,
+                REG_MSG_AMOUNT,
+                REG_BASE_ASSET_PTR,
+                RegId::CGAS,
+            ),
+            op::ret(RegId::ZERO),
+        ],
+    )
+}
+
+// Gets the bytecode for a message-to-contract script that calls
+// `process_message(msg_idx)` once for every `InputMessage` with data
+pub fn batch_bytecode() -> Vec<u8> {
+    let mut fn_selector_bytes =
+        fuels::core::codec::encode_fn_selector(PROCESS_MESSAGE_FUNCTION_SIGNATURE);
+
+    //register names
+    const REG_INPUTS_COUNT: u8 = 0x17;
+    const REG_INPUT_INDEX: u8 = 0x18;
+    const REG_INPUT_TYPE: u8 = 0x19;
+    const REG_INPUT_MSG_DATA_LEN: u8 = 0x1a;
+    const REG_EXPECTED_INPUT_TYPE: u8 = 0x1b;
+    const REG_NO_DATA: u8 = 0x1c;
+
+    //instruction jump points
+    const JMP_LOOP_START: u16 = 12;
+    const JMP_NEXT_INPUT: u16 = 21;
+
+    //referenced data start pointer
+    const REF_DATA_START_PTR: u16 = 24 * BYTES_PER_INSTR;
+
+    /* The following assembly code is intended to:
+     * For every `InputMessage` with data, call the function `process_message` with
+     * its input index on the contract with ID that matches the first 32 bytes in the
+     * message data field. Like the default script, it won't forward the possible
+     * value stored in the messages.
+     */
+    let mut script: Vec<u8> = call_frame(8) // [msg_idx]
+        .into_iter()
+        .chain(fn_selector_param(REF_DATA_START_PTR))
+        .chain([
+            op::addi(REG_CALLDATA_PTR, REG_DATA_PTR, 48), // REG_CALLDATA_PTR = REG_DATA_PTR + 48
+        ])
+        .chain(calldata_param())
+        .chain([
+            op::gtf(
+                REG_INPUTS_COUNT,
+                RegId::ZERO,
+                GTFArgs::ScriptInputsCount.into(),
+            ), //REG_INPUTS_COUNT = the number of inputs in the script
+            op::movi(REG_EXPECTED_INPUT_TYPE, INPUT_MESSAGE_TYPE), //REG_EXPECTED_INPUT_TYPE = INPUT_MESSAGE_TYPE
+            op::move_(REG_INPUT_INDEX, RegId::ZERO),               //REG_INPUT_INDEX = 0
+            //LOOP_START:
+            op::gtf(REG_INPUT_TYPE, REG_INPUT_INDEX, GTFArgs::InputType.into()), //REG_INPUT_TYPE = the type of input for input[INPUT_INDEX]
+            op::jnei(REG_INPUT_TYPE, REG_EXPECTED_INPUT_TYPE, JMP_NEXT_INPUT), //skips to NEXT_INPUT if REG_INPUT_TYPE does not equal REG_EXPECTED_INPUT_TYPE
+            op::gtf(
+                REG_INPUT_MSG_DATA_LEN,
+                REG_INPUT_INDEX,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_INPUT_MSG_DATA_LEN = the data length of input[INPUT_INDEX]
+            op::eq(REG_NO_DATA, REG_INPUT_MSG_DATA_LEN, RegId::ZERO), //REG_NO_DATA = if REG_INPUT_MSG_DATA_LEN equals 0
+            op::jnei(REG_NO_DATA, RegId::ZERO, JMP_NEXT_INPUT), //skips to NEXT_INPUT if the input message has no data
+        ])
+        .chain(contract_id_param(RegId::new(REG_INPUT_INDEX)))
+        .chain([
+            op::sw(REG_CALLDATA_PTR, REG_INPUT_INDEX, 0), // REG_DATA[48..55] = msg_idx = REG_INPUT_INDEX
+            op::call(REG_DATA_PTR, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            //NEXT_INPUT:
+            op::addi(REG_INPUT_INDEX, REG_INPUT_INDEX, 1), //REG_INPUT_INDEX = REG_INPUT_INDEX + 1
+            op::jnei(REG_INPUT_INDEX, REG_INPUTS_COUNT, JMP_LOOP_START), //jumps back to LOOP_START if REG_INPUT_INDEX does not equal REG_INPUTS_COUNT
+            op::ret(RegId::ZERO),
+        ])
+        .collect();
+
+    // At the tail of the script, after the return statement, embed:
+    script.append(&mut fn_selector_bytes); // function selector
+
+    script
+}
+
+// Gets the bytecode for a message-to-contract script that calls
+// `process_message(msg_idx = 0)` like the default script, but reports the call:
+// it logs the target contract id before calling it and returns `RELAY_DELIVERED`
+// once the call succeeded. A receiver failure aborts the script, so it's told
+// apart by the missing `RELAY_DELIVERED` return and the failing receipt of the
+// logged contract.
+pub fn reporting_bytecode() -> Vec<u8> {
+    //register names
+    const REG_LOG_ID: u8 = 0x17;
+    const REG_CONTRACT_ID_LEN: u8 = 0x18;
+    const REG_RESULT: u8 = 0x19;
+
+    /* The following assembly code is intended to:
+     * Log the contract with ID that matches the first 32 bytes in the message data
+     * field, then call the function `process_message` on it and return RELAY_DELIVERED.
+     * Like the default script, it won't forward the possible value stored in the message.
+     */
+    bytecode_with_call(
+        PROCESS_MESSAGE_FUNCTION_SIGNATURE,
+        &0u64.to_be_bytes(),
+        vec![
+            op::movi(REG_LOG_ID, RELAY_TARGET_LOG_ID), //REG_LOG_ID = RELAY_TARGET_LOG_ID
+            op::movi(REG_CONTRACT_ID_LEN, 32),         //REG_CONTRACT_ID_LEN = 32
+            op::logd(REG_LOG_ID, RegId::ZERO, REG_DATA_PTR, REG_CONTRACT_ID_LEN), //logs RELAY_TARGET_LOG_ID, msg_idx = 0 and REG_DATA[0..31]
+            op::call(REG_DATA_PTR, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::movi(REG_RESULT, RELAY_DELIVERED), //REG_RESULT = RELAY_DELIVERED
+            op::ret(REG_RESULT),
+        ],
+    )
+}
+
+// Gets the bytecode for a message-to-contract script that forwards the message
+// amount of base asset to the contract while calling `process_message(msg_idx = 0)`,
+// minus the relayer fee encoded as a big-endian u64 in the last `RELAYER_FEE_LEN`
+// bytes of the message data, after the payload of the receiver. The fee is capped
+// at the message amount, and is zero if the data is too short to hold both the
+// contract id and the fee. What isn't forwarded is left to the change outputs
+pub fn fee_capped_bytecode() -> Vec<u8> {
+    const MIN_DATA_LEN: u32 = 32 + RELAYER_FEE_LEN as u32;
+
+    //register names
+    const REG_MSG_AMOUNT: u8 = 0x17;
+    const REG_BASE_ASSET_PTR: u8 = 0x18;
+    const REG_FEE: u8 = 0x19;
+    const REG_MSG_DATA_LEN: u8 = 0x1a;
+    const REG_MIN_DATA_LEN: u8 = 0x1b;
+    const REG_CONDITION: u8 = 0x1c;
+    const REG_FORWARD_AMOUNT: u8 = 0x1d;
+    const REG_FEE_PTR: u8 = 0x1e;
+
+    //instruction jump points
+    const JMP_FORWARD: u16 = CALL_SETUP_LEN + 12;
+
+    /* The following assembly code is intended to:
+     * Call the function `process_message` on the contract with ID that matches
+     * the first 32 bytes in the message data field, forwarding the message `amount`
+     * of base asset minus the relayer fee found at the end of the message data,
+     * capped at the message amount.
+     */
+    bytecode_with_call(
+        PROCESS_MESSAGE_FUNCTION_SIGNATURE,
+        &0u64.to_be_bytes(),
+        vec![
+            op::gtf(
+                REG_MSG_AMOUNT,
+                RegId::ZERO,
+                GTFArgs::InputMessageAmount.into(),
+            ), //REG_MSG_AMOUNT = amount of the message from input[0]
+            //read the relayer fee
+            op::move_(REG_FEE, RegId::ZERO), //REG_FEE = 0
+            op::gtf(
+                REG_MSG_DATA_LEN,
+                RegId::ZERO,
+                GTFArgs::InputMessageDataLength.into(),
+            ), //REG_MSG_DATA_LEN = the data length of input[0]
+            op::movi(REG_MIN_DATA_LEN, MIN_DATA_LEN), //REG_MIN_DATA_LEN = length of the contract id and the fee
+            op::lt(REG_CONDITION, REG_MSG_DATA_LEN, REG_MIN_DATA_LEN), //REG_CONDITION = if the data is too short to hold the fee
+            op::jnei(REG_CONDITION, RegId::ZERO, JMP_FORWARD), //jumps to FORWARD with a zero fee if REG_CONDITION is not 0
+            op::add(REG_FEE_PTR, REG_CONTRACT_ADDR_PTR, REG_MSG_DATA_LEN), //REG_FEE_PTR = memory location of the end of the message data
+            op::subi(REG_FEE_PTR, REG_FEE_PTR, RELAYER_FEE_LEN as u16), //REG_FEE_PTR = memory location of the fee
+            op::lw(REG_FEE, REG_FEE_PTR, 0), //REG_FEE = message data[len - 8..len]
+            op::gt(REG_CONDITION, REG_FEE, REG_MSG_AMOUNT), //REG_CONDITION = if the fee is greater than the message amount
+            op::jnei(REG_CONDITION, RegId::ONE, JMP_FORWARD), //jumps to FORWARD if REG_CONDITION is not 1
+            op::move_(REG_FEE, REG_MSG_AMOUNT),               //REG_FEE = REG_MSG_AMOUNT
+            //FORWARD:
+            op::sub(REG_FORWARD_AMOUNT, REG_MSG_AMOUNT, REG_FEE), //REG_FORWARD_AMOUNT = REG_MSG_AMOUNT - REG_FEE
+            op::gm_args(REG_BASE_ASSET_PTR, GMArgs::BaseAssetId), //REG_BASE_ASSET_PTR = memory location of the base asset id
+            op::call(
+                REG_DATA_PTR,
+                REG_FORWARD_AMOUNT,
+                REG_BASE_ASSET_PTR,
+                RegId::CGAS,
+            ),
+            op::ret(RegId::ZERO),
+        ],
+    )
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use fuel_tx::{ConsensusParameters, Script, Word};
+use fuel_vm::{
+    checked_transaction::{CheckError, CheckPredicateParams, EstimatePredicates, IntoChecked},
+    error::PredicateVerificationFailed,
+    fuel_types::BlockHeight,
+    interpreter::{Interpreter, MemoryInstance},
+    storage::PredicateStorage,
+};
+
+/// Reason a transaction was rejected while evaluating its predicates
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvaluateError {
+    /// The transaction is malformed, regardless of what its predicates return
+    InvalidTransaction(CheckError),
+    /// A predicate rejected the transaction, e.g. by returning false
+    PredicateFailed(PredicateVerificationFailed),
+}
+
+impl fmt::Display for EvaluateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTransaction(e) => write!(f, "invalid transaction: {e:?}"),
+            Self::PredicateFailed(e) => write!(f, "predicate failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for EvaluateError {}
+
+impl From<CheckError> for EvaluateError {
+    fn from(e: CheckError) -> Self {
+        match e {
+            CheckError::PredicateVerificationFailed(e) => Self::PredicateFailed(e),
+            e => Self::InvalidTransaction(e),
+        }
+    }
+}
+
+/// Runs every predicate of the transaction with the fuel-vm interpreter, as a
+/// node would when validating it, without the need of a running node.
+///
+/// The gas used by each predicate is estimated first, so the transaction
+/// doesn't need to be estimated beforehand. Signatures are not checked.
+///
+/// Returns the total gas used by the predicates if all of them evaluate to
+/// true, or the reason the transaction was rejected.
+pub fn evaluate_predicates(
+    tx: &Script,
+    params: &ConsensusParameters,
+) -> Result<Word, EvaluateError> {
+    let predicate_params = CheckPredicateParams::from(params);
+
+    let mut tx = tx.clone();
+    tx.estimate_predicates(&predicate_params, MemoryInstance::new())?;
+    let checked = tx
+        .into_checked_basic(BlockHeight::default(), params)
+        .map_err(EvaluateError::InvalidTransaction)?;
+
+    let predicates =
+        Interpreter::<&mut MemoryInstance, PredicateStorage, Script>::check_predicates(
+            &checked,
+            &predicate_params,
+            MemoryInstance::new(),
+        )
+        .map_err(EvaluateError::PredicateFailed)?;
+
+    Ok(predicates.gas_used())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{
+        field::Inputs, Address, AssetId, Bytes32, ContractId, Input, Output, TransactionBuilder,
+        TxPointer, UtxoId,
+    };
+    use fuel_vm::{fuel_types::Nonce, prelude::PanicReason};
+
+    use super::*;
+
+    fn message(index: u8, data: Vec<u8>) -> Input {
+        let predicate = crate::predicate_bytecode();
+        let recipient = Input::predicate_owner(&predicate);
+        let nonce = Nonce::from([index; 32]);
+        if data.is_empty() {
+            Input::message_coin_predicate(
+                Address::zeroed(),
+                recipient,
+                100,
+                nonce,
+                0,
+                predicate,
+                vec![],
+            )
+        } else {
+            Input::message_data_predicate(
+                Address::zeroed(),
+                recipient,
+                100,
+                nonce,
+                0,
+                data,
+                predicate,
+                vec![],
+            )
+        }
+    }
+
+    fn relay_tx(script: Vec<u8>, inputs: Vec<Input>) -> Script {
+        let mut builder = TransactionBuilder::script(script, vec![]);
+        builder.script_gas_limit(1_000_000);
+        for input in inputs {
+            builder.add_input(input);
+        }
+        builder.add_output(Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()));
+        builder.add_output(Output::change(Address::zeroed(), 0, AssetId::BASE));
+        builder.finalize_without_signature_inner()
+    }
+
+    fn contract() -> Input {
+        Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            ContractId::zeroed(),
+        )
+    }
+
+    #[test]
+    fn accepts_relay_tx() {
+        let tx = relay_tx(
+            crate::script_bytecode(),
+            vec![message(0, vec![0u8; 32]), contract(), message(2, vec![])],
+        );
+
+        let gas_used = evaluate_predicates(&tx, &ConsensusParameters::standard()).unwrap();
+        assert!(gas_used > 0);
+        // the evaluated transaction is left untouched
+        assert!(tx
+            .inputs()
+            .iter()
+            .filter_map(Input::predicate_gas_used)
+            .all(|gas| gas == 0));
+    }
+
+    #[test]
+    fn rejects_foreign_script() {
+        let tx = relay_tx(
+            vec![0u8; 4],
+            vec![message(0, vec![0u8; 32]), contract(), message(2, vec![])],
+        );
+
+        let result = evaluate_predicates(&tx, &ConsensusParameters::standard());
+        assert_eq!(
+            result,
+            Err(EvaluateError::PredicateFailed(
+                PredicateVerificationFailed::Panic(PanicReason::PredicateReturnedNonOne)
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_second_message_with_data() {
+        let tx = relay_tx(
+            crate::script_bytecode(),
+            vec![
+                message(0, vec![0u8; 32]),
+                contract(),
+                message(2, vec![0u8; 32]),
+                message(3, vec![]),
+            ],
+        );
+
+        let result = evaluate_predicates(&tx, &ConsensusParameters::standard());
+        assert_eq!(
+            result,
+            Err(EvaluateError::PredicateFailed(
+                PredicateVerificationFailed::Panic(PanicReason::PredicateReturnedNonOne)
+            ))
+        );
+    }
+
+    #[test]
+    fn tells_malformed_tx_apart() {
+        // the contract output points at a message instead of a contract input
+        let tx = relay_tx(
+            crate::script_bytecode(),
+            vec![message(0, vec![0u8; 32]), message(1, vec![])],
+        );
+
+        let result = evaluate_predicates(&tx, &ConsensusParameters::standard());
+        assert!(matches!(
+            result,
+            Err(EvaluateError::InvalidTransaction(CheckError::Validity(_)))
+        ));
+    }
+}
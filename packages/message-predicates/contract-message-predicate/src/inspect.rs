@@ -0,0 +1,355 @@
+use std::fmt;
+
+use fuel_asm::{Instruction, Opcode, RegId};
+
+use crate::{PREDICATE_BYTECODE, SCRIPT_ASM};
+
+const BYTES_PER_INSTR: usize = 4;
+
+/// Disassembly of the message-to-contract script and predicate, together with
+/// the result of their structural checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectReport {
+    pub script: ProgramReport,
+    pub predicate: ProgramReport,
+    /// Script hash embedded in the trailing 32 bytes of the predicate
+    pub embedded_script_hash: [u8; 32],
+    /// Hash of the script the predicate is shipped with
+    pub script_hash: [u8; 32],
+}
+
+impl InspectReport {
+    /// Whether the predicate pins the script it is shipped with
+    pub fn script_hash_matches(&self) -> bool {
+        self.embedded_script_hash == self.script_hash
+    }
+
+    /// Whether all the structural checks passed
+    pub fn is_valid(&self) -> bool {
+        self.script_hash_matches()
+            && self.script.jumps_in_bounds()
+            && self.predicate.jumps_in_bounds()
+    }
+}
+
+/// Disassembly of a single program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramReport {
+    pub instructions: Vec<DecodedInstruction>,
+    /// Referenced data embedded after the last instruction
+    pub data: Vec<u8>,
+}
+
+impl ProgramReport {
+    /// Jumps whose target can be resolved without executing the program
+    pub fn static_jumps(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.instructions
+            .iter()
+            .filter_map(|instr| instr.jump_target.map(|target| (instr.index, target)))
+    }
+
+    /// Whether every static jump lands on an instruction of the program
+    pub fn jumps_in_bounds(&self) -> bool {
+        self.static_jumps()
+            .all(|(_, target)| target < self.instructions.len())
+    }
+}
+
+/// An instruction along with its position and a human readable form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    pub index: usize,
+    pub instruction: Instruction,
+    /// Assembly with named registers, e.g. `addi $r19 $is 44`
+    pub annotation: String,
+    /// Index of the instruction a jump lands on, if known statically
+    pub jump_target: Option<usize>,
+}
+
+/// Error raised when the code section of a program can't be disassembled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectError {
+    InvalidInstruction { index: usize },
+    TruncatedInstruction { index: usize },
+}
+
+impl fmt::Display for InspectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInstruction { index } => write!(f, "invalid instruction at index {index}"),
+            Self::TruncatedInstruction { index } => {
+                write!(f, "truncated instruction at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InspectError {}
+
+/// Disassembles the shipped script and predicate and checks that the predicate
+/// pins the script and that every jump target is in bounds
+pub fn inspect() -> Result<InspectReport, InspectError> {
+    inspect_pair(SCRIPT_ASM, PREDICATE_BYTECODE)
+}
+
+/// Same as [`inspect`] for any script and predicate pair whose predicate ends
+/// with the expected script hash
+pub fn inspect_pair(script: &[u8], predicate: &[u8]) -> Result<InspectReport, InspectError> {
+    let script_report = disassemble(script)?;
+    let predicate_report = disassemble(predicate)?;
+
+    let mut embedded_script_hash = [0u8; 32];
+    if let Some(tail) = predicate
+        .len()
+        .checked_sub(32)
+        .map(|start| &predicate[start..])
+    {
+        embedded_script_hash.copy_from_slice(tail);
+    }
+
+    Ok(InspectReport {
+        script: script_report,
+        predicate: predicate_report,
+        embedded_script_hash,
+        script_hash: crate::codegen::script_hash(script),
+    })
+}
+
+/// Disassembles a program into its instructions and trailing referenced data.
+///
+/// The code section ends where the first `addi $rX $is imm` reference points
+/// to, or at the end of the bytecode if the program references no data.
+pub fn disassemble(bytecode: &[u8]) -> Result<ProgramReport, InspectError> {
+    let mut data_start = bytecode.len();
+    let mut instructions = Vec::new();
+
+    let mut offset = 0;
+    while offset < data_start {
+        let index = offset / BYTES_PER_INSTR;
+        let word: [u8; BYTES_PER_INSTR] = bytecode
+            .get(offset..offset + BYTES_PER_INSTR)
+            .and_then(|word| word.try_into().ok())
+            .ok_or(InspectError::TruncatedInstruction { index })?;
+        let instruction =
+            Instruction::try_from(word).map_err(|_| InspectError::InvalidInstruction { index })?;
+
+        if let Instruction::ADDI(addi) = instruction {
+            let (_, base, imm) = addi.unpack();
+            if base == RegId::IS {
+                data_start = data_start.min(usize::from(u16::from(imm)));
+            }
+        }
+
+        instructions.push(DecodedInstruction {
+            index,
+            instruction,
+            annotation: annotate(instruction),
+            jump_target: jump_target(index, instruction),
+        });
+        offset += BYTES_PER_INSTR;
+    }
+
+    Ok(ProgramReport {
+        instructions,
+        data: bytecode[offset..].to_vec(),
+    })
+}
+
+// Gets the index an instruction jumps to, if it doesn't depend on a register
+fn jump_target(index: usize, instruction: Instruction) -> Option<usize> {
+    let forwards =
+        |dynamic: RegId, fixed: u32| (dynamic == RegId::ZERO).then(|| index + fixed as usize + 1);
+    let backwards = |dynamic: RegId, fixed: u32| {
+        // a target before the first instruction is never in bounds
+        (dynamic == RegId::ZERO)
+            .then(|| index.checked_sub(fixed as usize + 1).unwrap_or(usize::MAX))
+    };
+
+    match instruction {
+        Instruction::JI(ji) => Some(u32::from(ji.unpack()) as usize),
+        Instruction::JNEI(jnei) => Some(usize::from(u16::from(jnei.unpack().2))),
+        Instruction::JNZI(jnzi) => Some(u32::from(jnzi.unpack().1) as usize),
+        Instruction::JMPF(jmpf) => {
+            let (dynamic, fixed) = jmpf.unpack();
+            forwards(dynamic, fixed.into())
+        }
+        Instruction::JMPB(jmpb) => {
+            let (dynamic, fixed) = jmpb.unpack();
+            backwards(dynamic, fixed.into())
+        }
+        Instruction::JNZF(jnzf) => {
+            let (_, dynamic, fixed) = jnzf.unpack();
+            forwards(dynamic, u16::from(fixed).into())
+        }
+        Instruction::JNZB(jnzb) => {
+            let (_, dynamic, fixed) = jnzb.unpack();
+            backwards(dynamic, u16::from(fixed).into())
+        }
+        Instruction::JNEF(jnef) => {
+            let (_, _, dynamic, fixed) = jnef.unpack();
+            forwards(dynamic, u8::from(fixed).into())
+        }
+        Instruction::JNEB(jneb) => {
+            let (_, _, dynamic, fixed) = jneb.unpack();
+            backwards(dynamic, u8::from(fixed).into())
+        }
+        _ => None,
+    }
+}
+
+// Renders an instruction as assembly, naming the reserved registers
+fn annotate(instruction: Instruction) -> String {
+    let opcode = instruction.opcode();
+    let mut parts = vec![format!("{opcode:?}").to_lowercase()];
+
+    let registers: Vec<RegId> = instruction.reg_ids().into_iter().flatten().collect();
+    parts.extend(registers.iter().map(|reg| register_name(*reg)));
+
+    if has_immediate(opcode) {
+        let raw = u32::from_be_bytes(instruction.into());
+        let imm_bits = 24 - 6 * registers.len() as u32;
+        parts.push((raw & ((1 << imm_bits) - 1)).to_string());
+    }
+
+    parts.join(" ")
+}
+
+// Gets the assembly name of a register
+fn register_name(reg: RegId) -> String {
+    let name = match reg {
+        RegId::ZERO => "zero",
+        RegId::ONE => "one",
+        RegId::OF => "of",
+        RegId::PC => "pc",
+        RegId::SSP => "ssp",
+        RegId::SP => "sp",
+        RegId::FP => "fp",
+        RegId::HP => "hp",
+        RegId::ERR => "err",
+        RegId::GGAS => "ggas",
+        RegId::CGAS => "cgas",
+        RegId::BAL => "bal",
+        RegId::IS => "is",
+        RegId::RET => "ret",
+        RegId::RETL => "retl",
+        RegId::FLAG => "flag",
+        _ => return format!("$r{}", reg.to_u8()),
+    };
+    format!("${name}")
+}
+
+// Whether the opcode carries an immediate value after its registers
+fn has_immediate(opcode: Opcode) -> bool {
+    use Opcode::*;
+    matches!(
+        opcode,
+        LDC | ADDI
+            | ANDI
+            | DIVI
+            | EXPI
+            | MODI
+            | MULI
+            | ORI
+            | SLLI
+            | SRLI
+            | SUBI
+            | XORI
+            | JNEI
+            | LB
+            | LW
+            | SB
+            | SW
+            | MCPI
+            | GTF
+            | MCLI
+            | GM
+            | MOVI
+            | JNZI
+            | JMPF
+            | JMPB
+            | JNZF
+            | JNZB
+            | JNEF
+            | JNEB
+            | JI
+            | CFEI
+            | CFSI
+            | PSHL
+            | PSHH
+            | POPL
+            | POPH
+            | WDCM
+            | WQCM
+            | WDOP
+            | WQOP
+            | WDML
+            | WQML
+            | WDDV
+            | WQDV
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::op;
+
+    use super::*;
+    use crate::codegen::predicate_asm;
+
+    #[test]
+    fn shipped_pair_is_valid() {
+        let report = inspect().unwrap();
+
+        assert!(report.script_hash_matches());
+        assert!(report.is_valid());
+        assert_eq!(report.embedded_script_hash, crate::script_hash());
+        assert_eq!(report.predicate.data, crate::script_hash());
+        assert_eq!(report.script.instructions[0].annotation, "move $r16 $sp");
+    }
+
+    #[test]
+    fn shipped_v2_pair_is_valid() {
+        let report = inspect_pair(SCRIPT_ASM, crate::PREDICATE_V2_BYTECODE).unwrap();
+
+        assert!(report.is_valid());
+        assert_eq!(report.embedded_script_hash, crate::script_hash());
+        assert_eq!(report.predicate.instructions.len(), 19);
+    }
+
+    #[test]
+    fn detects_foreign_script() {
+        let script = crate::RelayScript::any_input().script;
+        let report = inspect_pair(&script, PREDICATE_BYTECODE).unwrap();
+
+        assert!(!report.script_hash_matches());
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn detects_out_of_bounds_jump() {
+        let program: Vec<u8> = vec![op::jnei(0x10, RegId::ZERO, 3), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect();
+        let report = disassemble(&program).unwrap();
+
+        assert_eq!(report.static_jumps().collect::<Vec<_>>(), vec![(0, 3)]);
+        assert!(!report.jumps_in_bounds());
+    }
+
+    #[test]
+    fn variants_are_valid() {
+        for relay in [
+            crate::RelayScript::any_input(),
+            crate::RelayScript::value_forwarding(),
+            crate::RelayScript::batch(),
+            crate::RelayScript::reporting(),
+            crate::RelayScript::fee_capped(),
+        ] {
+            let report = inspect_pair(&relay.script, &relay.predicate).unwrap();
+            assert!(report.is_valid());
+        }
+
+        let predicate = predicate_asm::any_input_bytecode();
+        assert_eq!(disassemble(&predicate).unwrap().instructions.len(), 23);
+    }
+}
@@ -1,4 +1,20 @@
-use fuel_tx::Input;
+use fuel_tx::{ConsensusParameters, Input};
+use fuel_vm::fuel_types::ChainId;
+
+pub mod codegen;
+pub mod evaluate;
+pub mod inspect;
+pub mod manifest;
+pub mod message;
+pub mod outcome;
+mod relay_script;
+pub mod relay_tx;
+mod validate;
+pub mod versions;
+
+pub use relay_script::{RelayScript, RelayScriptBuilder};
+pub use relay_tx::RelayTxBuilder;
+pub use validate::{validate_relay_tx, RelayTxError};
 
 pub const SCRIPT_HASH: &[u8] = include_bytes!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -12,6 +28,10 @@ pub const PREDICATE_BYTECODE: &[u8] = include_bytes!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/out/contract_message_predicate.bin"
 ));
+pub const PREDICATE_V2_BYTECODE: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/out/contract_message_predicate_v2.bin"
+));
 pub const DEFAULT_PREDICATE_ROOT: &[u8] = include_bytes!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/out/contract_message_predicate_default_root.bin"
@@ -27,6 +47,13 @@ pub fn predicate_bytecode() -> Vec<u8> {
     PREDICATE_BYTECODE.to_vec()
 }
 
+// Gets the bytecode for the gas-optimized message-to-contract predicate, which
+// pins the same script hash. Unlike the original predicate, it also requires
+// input 0 to be a message
+pub fn predicate_v2_bytecode() -> Vec<u8> {
+    PREDICATE_V2_BYTECODE.to_vec()
+}
+
 // Gets the hash of the message-to-contract script
 pub fn script_hash() -> [u8; 32] {
     SCRIPT_HASH
@@ -40,6 +67,38 @@ pub fn predicate_root() -> [u8; 32] {
     root.into()
 }
 
+// Gets the root of the gas-optimized message-to-contract predicate
+pub fn predicate_v2_root() -> [u8; 32] {
+    let root = Input::predicate_owner(PREDICATE_V2_BYTECODE);
+    root.into()
+}
+
+// Gets the precomputed root of the message-to-contract predicate for chain_id=0
+pub fn default_predicate_root() -> [u8; 32] {
+    DEFAULT_PREDICATE_ROOT
+        .try_into()
+        .expect("Should be checked at compile time")
+}
+
+/// Build artifacts of the message-to-contract predicate for a given chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateArtifacts {
+    pub bytecode: Vec<u8>,
+    pub root: [u8; 32],
+    pub script_hash: [u8; 32],
+    pub chain_id: ChainId,
+}
+
+// Gets the predicate artifacts for the chain of the given consensus parameters
+pub fn predicate_artifacts(params: &ConsensusParameters) -> PredicateArtifacts {
+    PredicateArtifacts {
+        bytecode: predicate_bytecode(),
+        root: predicate_root(),
+        script_hash: script_hash(),
+        chain_id: params.chain_id(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,4 +118,80 @@ mod tests {
         let serialized = hex::encode(bytecode);
         insta::assert_snapshot!(serialized);
     }
+
+    // Ensure the gas-optimized predicate bytecode doesn't change
+    #[test]
+    fn snapshot_predicate_v2_bytecode() {
+        let bytecode = predicate_v2_bytecode();
+        let serialized = hex::encode(bytecode);
+        insta::assert_snapshot!(serialized);
+    }
+
+    // Ensure the bytecode of released versions doesn't change
+    #[test]
+    fn snapshot_versions() {
+        for version in versions::VERSIONS {
+            insta::assert_snapshot!(
+                format!("script_v{}", version.version),
+                hex::encode(version.script)
+            );
+            insta::assert_snapshot!(
+                format!("predicate_v{}", version.version),
+                hex::encode(version.predicate)
+            );
+        }
+    }
+
+    // Ensure a change to the build artifacts is released as a new version
+    #[test]
+    fn current_version_matches_build_artifacts() {
+        let current = versions::current();
+
+        assert_eq!(current.script, SCRIPT_ASM);
+        assert_eq!(current.script_hash, script_hash());
+        assert_eq!(current.predicate, PREDICATE_BYTECODE);
+        assert_eq!(current.root, predicate_root());
+
+        let v2 = versions::version_for_root(predicate_v2_root().into()).unwrap();
+        assert_eq!(v2.script, SCRIPT_ASM);
+        assert_eq!(v2.predicate, PREDICATE_V2_BYTECODE);
+    }
+
+    // Ensure the stored default root didn't drift from the predicate bytecode
+    #[test]
+    fn default_root_matches_computed_root() {
+        assert_eq!(default_predicate_root(), predicate_root());
+        assert_eq!(
+            default_predicate_root(),
+            <[u8; 32]>::from(Input::predicate_owner(codegen::predicate_asm::bytecode()))
+        );
+    }
+
+    #[test]
+    fn predicate_artifacts_for_chain() {
+        let chain_id = ChainId::new(9889);
+        let artifacts = predicate_artifacts(&ConsensusParameters::standard_with_id(chain_id));
+
+        assert_eq!(artifacts.chain_id, chain_id);
+        assert_eq!(artifacts.bytecode, PREDICATE_BYTECODE);
+        assert_eq!(artifacts.script_hash, script_hash());
+        // the predicate root doesn't depend on the chain
+        assert_eq!(
+            artifacts.root,
+            predicate_artifacts(&ConsensusParameters::standard()).root
+        );
+        assert_eq!(artifacts.root, default_predicate_root());
+    }
+
+    // Ensure the runtime generators match the cached build artifacts
+    #[test]
+    fn codegen_matches_build_artifacts() {
+        let script = codegen::script_asm::bytecode();
+        let predicate = codegen::predicate_asm::bytecode();
+
+        assert_eq!(script, SCRIPT_ASM);
+        assert_eq!(codegen::script_hash(&script), script_hash());
+        assert_eq!(predicate, PREDICATE_BYTECODE);
+        assert_eq!(codegen::predicate_asm::bytecode_v2(), PREDICATE_V2_BYTECODE);
+    }
 }
@@ -1,6 +1,127 @@
-use fuel_tx::{Address, Bytes32};
+use std::{fs, path::PathBuf, str::FromStr};
+
+use clap::{Args, Parser, Subcommand};
+use fuel_contract_message_predicate::{
+    inspect::{self, ProgramReport},
+    manifest,
+    message::BridgeMessage,
+    relay_tx::{self, RelayTxBuilder},
+    versions,
+};
+use fuel_tx::{
+    Address, AssetId, Bytes32, ConsensusParameters, ContractId, Input, Transaction, Word,
+};
+use fuel_vm::fuel_types::{ChainId, Nonce};
+use fuels::prelude::TxPolicies;
+
+#[derive(Parser)]
+#[command(about = "Script and predicate for relaying contract messages")]
+struct Cli {
+    /// Print the manifest of the script and predicate as JSON
+    #[arg(long)]
+    manifest: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Disassemble the script and predicate and verify their structure
+    Inspect,
+    /// Print the predicate root for a given chain
+    Root {
+        #[arg(long, default_value_t = 0)]
+        chain_id: u64,
+    },
+    /// Compute the id of a message
+    MessageId {
+        #[command(flatten)]
+        message: MessageArgs,
+        /// Recipient of the message, the predicate root otherwise
+        #[arg(long)]
+        recipient: Option<Address>,
+    },
+    /// Write an unsigned transaction relaying a message to the given contracts as JSON.
+    /// Gas coins and signatures are left to the relayer.
+    BuildRelay {
+        #[command(flatten)]
+        message: MessageArgs,
+        /// Contracts the message interacts with
+        #[arg(long = "contract")]
+        contracts: Vec<ContractId>,
+        /// Recipient of unspent coins
+        #[arg(long, default_value_t = Address::zeroed())]
+        change_to: Address,
+        /// Base asset of the chain, returned as change
+        #[arg(long, default_value_t = AssetId::BASE)]
+        base_asset_id: AssetId,
+        #[arg(long, default_value_t = 1_000_000)]
+        script_gas_limit: Word,
+        /// File to write the transaction to, stdout otherwise
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Check a hex encoded predicate against the built-in one
+    Verify { predicate: HexBytes },
+}
+
+#[derive(Args)]
+struct MessageArgs {
+    #[arg(long)]
+    sender: Address,
+    #[arg(long)]
+    nonce: Nonce,
+    #[arg(long)]
+    amount: Word,
+    /// Hex encoded message data, starting with the receiving contract id
+    #[arg(long, default_value = "")]
+    data: HexBytes,
+}
+
+// Bytes given as hex on the command line, with or without a 0x prefix
+#[derive(Clone)]
+struct HexBytes(Vec<u8>);
+
+impl FromStr for HexBytes {
+    type Err = hex::FromHexError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        hex::decode(value.trim_start_matches("0x")).map(Self)
+    }
+}
 
 fn main() {
+    let cli = Cli::parse();
+    if cli.manifest {
+        println!("{}", manifest::manifest().to_json());
+        return;
+    }
+
+    match cli.command {
+        None => summary(),
+        Some(Command::Inspect) => inspect(),
+        Some(Command::Root { chain_id }) => root(chain_id),
+        Some(Command::MessageId { message, recipient }) => message_id(message, recipient),
+        Some(Command::BuildRelay {
+            message,
+            contracts,
+            change_to,
+            base_asset_id,
+            script_gas_limit,
+            output,
+        }) => build_relay(
+            message,
+            contracts,
+            change_to,
+            base_asset_id,
+            script_gas_limit,
+            output,
+        ),
+        Some(Command::Verify { predicate }) => verify(predicate.0),
+    }
+}
+
+fn summary() {
     //get predicate and script bytecode
     let script = fuel_contract_message_predicate::script_bytecode();
     let predicate = fuel_contract_message_predicate::predicate_bytecode();
@@ -13,3 +134,119 @@ fn main() {
     println!("Predicate bytecode size is {} bytes.", predicate.len());
     println!("Predicate root: 0x{}", Address::from(predicate_root));
 }
+
+fn inspect() {
+    let report = match inspect::inspect() {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Failed to disassemble: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("Script:");
+    print_program(&report.script);
+    println!("Predicate:");
+    print_program(&report.predicate);
+
+    println!("Script hash: 0x{}", Bytes32::from(report.script_hash));
+    println!(
+        "Embedded script hash: 0x{}",
+        Bytes32::from(report.embedded_script_hash)
+    );
+    println!("Script hash matches: {}", report.script_hash_matches());
+    println!(
+        "Jump targets in bounds: {}",
+        report.script.jumps_in_bounds() && report.predicate.jumps_in_bounds()
+    );
+
+    if !report.is_valid() {
+        std::process::exit(1);
+    }
+}
+
+fn root(chain_id: u64) {
+    let params = ConsensusParameters::standard_with_id(ChainId::new(chain_id));
+    let artifacts = fuel_contract_message_predicate::predicate_artifacts(&params);
+
+    println!("Chain id: {}", *artifacts.chain_id);
+    println!("Predicate root: 0x{}", Address::from(artifacts.root));
+}
+
+fn message_id(message: MessageArgs, recipient: Option<Address>) {
+    let recipient = recipient
+        .unwrap_or_else(|| Address::from(fuel_contract_message_predicate::predicate_root()));
+    let id = Input::compute_message_id(
+        &message.sender,
+        &recipient,
+        &message.nonce,
+        message.amount,
+        &message.data.0,
+    );
+
+    println!("Message id: 0x{id}");
+}
+
+fn build_relay(
+    message: MessageArgs,
+    contracts: Vec<ContractId>,
+    change_to: Address,
+    base_asset_id: AssetId,
+    script_gas_limit: Word,
+    output: Option<PathBuf>,
+) {
+    let message = BridgeMessage::to_contract(
+        message.sender,
+        message.nonce,
+        message.amount,
+        message.data.0,
+    );
+    let tx = RelayTxBuilder::new(message.to_input())
+        .with_contracts(contracts.into_iter().map(relay_tx::contract_input))
+        .with_change_to(change_to)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(script_gas_limit))
+        .build_unsigned(base_asset_id)
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to build the relay transaction: {e}");
+            std::process::exit(1);
+        });
+
+    let tx = Transaction::from(tx);
+    let json = serde_json::to_string_pretty(&tx).expect("Transaction is always serializable");
+    match output {
+        Some(path) => fs::write(&path, json).unwrap_or_else(|e| {
+            eprintln!("Failed to write transaction to {path:?}: {e}");
+            std::process::exit(1);
+        }),
+        None => println!("{json}"),
+    }
+}
+
+fn verify(predicate: Vec<u8>) {
+    let root = Input::predicate_owner(&predicate);
+    println!("Predicate root: 0x{root}");
+
+    if predicate == fuel_contract_message_predicate::predicate_bytecode() {
+        println!("Matches the built-in predicate.");
+        return;
+    }
+
+    match versions::version_for_root(root) {
+        Some(version) => println!(
+            "Does not match the built-in predicate, but matches released version {}.",
+            version.version
+        ),
+        None => println!("Does not match the built-in predicate or any released version."),
+    }
+    std::process::exit(1);
+}
+
+fn print_program(program: &ProgramReport) {
+    for instr in &program.instructions {
+        match instr.jump_target {
+            Some(target) => println!("  {:>4}: {} -> {target}", instr.index, instr.annotation),
+            None => println!("  {:>4}: {}", instr.index, instr.annotation),
+        }
+    }
+    println!("  data: 0x{}", hex::encode(&program.data));
+}
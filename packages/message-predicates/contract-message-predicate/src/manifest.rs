@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    predicate_bytecode, predicate_root, script_bytecode, script_hash,
+    versions::{PredicateVersion, VERSIONS},
+};
+
+// Sources of the generators, hashed into the generator fingerprint
+const GENERATOR_SOURCES: &[&str] = &[
+    include_str!("codegen/mod.rs"),
+    include_str!("codegen/script_asm.rs"),
+    include_str!("codegen/predicate_asm.rs"),
+];
+
+/// Description of the message-to-contract script and predicate that belong
+/// together, for consumers that don't link against this crate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub crate_version: String,
+    pub generator_fingerprint: String,
+    pub script: ProgramManifest,
+    pub script_hash: String,
+    pub predicate: ProgramManifest,
+    pub predicate_root: String,
+    /// Every released script and predicate pair, see [`crate::versions`]
+    pub versions: Vec<VersionManifest>,
+}
+
+/// A released script and predicate pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionManifest {
+    pub version: u16,
+    pub script: ProgramManifest,
+    pub script_hash: String,
+    pub predicate: ProgramManifest,
+    pub predicate_root: String,
+}
+
+impl From<&PredicateVersion> for VersionManifest {
+    fn from(version: &PredicateVersion) -> Self {
+        Self {
+            version: version.version,
+            script: ProgramManifest::new(version.script),
+            script_hash: prefixed_hex(&version.script_hash),
+            predicate: ProgramManifest::new(version.predicate),
+            predicate_root: prefixed_hex(&version.root),
+        }
+    }
+}
+
+/// Bytecode of a single program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramManifest {
+    pub bytecode: String,
+    pub size: usize,
+}
+
+impl ProgramManifest {
+    fn new(bytecode: &[u8]) -> Self {
+        Self {
+            bytecode: prefixed_hex(bytecode),
+            size: bytecode.len(),
+        }
+    }
+}
+
+impl Manifest {
+    // Serializes the manifest as pretty printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Manifest is always serializable")
+    }
+}
+
+// Gets the manifest of the current build artifacts
+pub fn manifest() -> Manifest {
+    Manifest {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        generator_fingerprint: prefixed_hex(&generator_fingerprint()),
+        script: ProgramManifest::new(&script_bytecode()),
+        script_hash: prefixed_hex(&script_hash()),
+        predicate: ProgramManifest::new(&predicate_bytecode()),
+        predicate_root: prefixed_hex(&predicate_root()),
+        versions: VERSIONS.iter().map(VersionManifest::from).collect(),
+    }
+}
+
+// Gets the hash of the generator sources the artifacts were built from
+pub fn generator_fingerprint() -> [u8; 32] {
+    fingerprint(GENERATOR_SOURCES)
+}
+
+// Hashes each source prefixed with its length, so that moving code from one
+// source to the next changes the fingerprint
+fn fingerprint(sources: &[&str]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for source in sources {
+        hasher.update((source.len() as u64).to_be_bytes());
+        hasher.update(source.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+fn prefixed_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_round_trips_through_json() {
+        let manifest = manifest();
+        let parsed: Manifest = serde_json::from_str(&manifest.to_json()).unwrap();
+
+        assert_eq!(parsed, manifest);
+    }
+
+    #[test]
+    fn manifest_describes_build_artifacts() {
+        let manifest = manifest();
+        let script = hex::decode(manifest.script.bytecode.trim_start_matches("0x")).unwrap();
+        let predicate = hex::decode(manifest.predicate.bytecode.trim_start_matches("0x")).unwrap();
+
+        assert_eq!(script, script_bytecode());
+        assert_eq!(manifest.script.size, script.len());
+        assert_eq!(predicate, predicate_bytecode());
+        assert_eq!(manifest.predicate.size, predicate.len());
+        assert_eq!(
+            manifest.script_hash,
+            prefixed_hex(&crate::codegen::script_hash(&script))
+        );
+        let root: [u8; 32] = fuel_tx::Input::predicate_owner(&predicate).into();
+        assert_eq!(manifest.predicate_root, prefixed_hex(&root));
+    }
+
+    #[test]
+    fn manifest_lists_every_version() {
+        let manifest = manifest();
+
+        assert_eq!(manifest.versions.len(), VERSIONS.len());
+        for (entry, version) in manifest.versions.iter().zip(VERSIONS) {
+            assert_eq!(entry.version, version.version);
+            assert_eq!(entry.predicate.size, version.predicate.len());
+            assert_eq!(entry.predicate_root, prefixed_hex(&version.root));
+        }
+        let current = crate::versions::current();
+        assert!(manifest
+            .versions
+            .iter()
+            .any(|entry| entry.predicate_root == manifest.predicate_root
+                && entry.version == current.version));
+    }
+
+    #[test]
+    fn fingerprint_changes_with_the_generators() {
+        let fingerprint_hex = prefixed_hex(&generator_fingerprint());
+        assert_eq!(manifest().generator_fingerprint, fingerprint_hex);
+
+        // editing any generator source changes the fingerprint
+        for index in 0..GENERATOR_SOURCES.len() {
+            let edited = format!("{}\n", GENERATOR_SOURCES[index]);
+            let mut sources = GENERATOR_SOURCES.to_vec();
+            sources[index] = &edited;
+            assert_ne!(fingerprint(&sources), generator_fingerprint());
+        }
+
+        // so does moving code between them
+        let moved = [
+            format!("{}{}", GENERATOR_SOURCES[0], GENERATOR_SOURCES[1]),
+            String::new(),
+        ];
+        let mut sources = GENERATOR_SOURCES.to_vec();
+        sources[0] = &moved[0];
+        sources[1] = &moved[1];
+        assert_ne!(fingerprint(&sources), generator_fingerprint());
+    }
+}
@@ -0,0 +1,169 @@
+use fuel_tx::{Address, ContractId, MessageId, Word};
+use fuel_vm::fuel_types::Nonce;
+use fuels::types::{
+    coin_type::CoinType,
+    input::Input,
+    message::{Message, MessageStatus},
+};
+
+use crate::{predicate_root, versions};
+
+/// A message sent from the base layer through the message portal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BridgeMessage {
+    pub sender: Address,
+    pub recipient: Address,
+    pub nonce: Nonce,
+    pub amount: Word,
+    pub data: Vec<u8>,
+}
+
+impl BridgeMessage {
+    /// Creates a message to a contract, owned by the contract message
+    /// predicate. The data must start with the id of the receiving contract.
+    pub fn to_contract(sender: Address, nonce: Nonce, amount: Word, data: Vec<u8>) -> Self {
+        Self {
+            sender,
+            recipient: Address::from(predicate_root()),
+            nonce,
+            amount,
+            data,
+        }
+    }
+
+    /// Computes the id of the message, as done by fuel-tx
+    pub fn message_id(&self) -> MessageId {
+        fuel_tx::Input::compute_message_id(
+            &self.sender,
+            &self.recipient,
+            &self.nonce,
+            self.amount,
+            &self.data,
+        )
+    }
+
+    /// Checks if the message is addressed to any released version of the
+    /// contract message predicate
+    pub fn is_contract_message(&self) -> bool {
+        versions::version_for_root(self.recipient).is_some()
+    }
+
+    /// Gets the id of the receiving contract of a contract message
+    pub fn contract_id(&self) -> Option<ContractId> {
+        if !self.is_contract_message() {
+            return None;
+        }
+        let id: [u8; 32] = self.data.get(..32)?.try_into().ok()?;
+        Some(ContractId::from(id))
+    }
+
+    /// Gets the input spending the message, through the version of the
+    /// contract message predicate it's addressed to for contract messages, or
+    /// signed by the recipient otherwise
+    pub fn to_input(&self) -> Input {
+        let resource = CoinType::Message(self.clone().into());
+        match versions::version_for_root(self.recipient) {
+            Some(version) => {
+                Input::resource_predicate(resource, version.predicate.to_vec(), vec![])
+            }
+            None => Input::resource_signed(resource),
+        }
+    }
+}
+
+impl From<BridgeMessage> for Message {
+    fn from(message: BridgeMessage) -> Self {
+        Self {
+            amount: message.amount,
+            sender: message.sender.into(),
+            recipient: message.recipient.into(),
+            nonce: message.nonce,
+            data: message.data,
+            da_height: 0,
+            status: MessageStatus::Unspent,
+        }
+    }
+}
+
+impl From<Message> for BridgeMessage {
+    fn from(message: Message) -> Self {
+        Self {
+            sender: (&message.sender).into(),
+            recipient: (&message.recipient).into(),
+            nonce: message.nonce,
+            amount: message.amount,
+            data: message.data,
+        }
+    }
+}
+
+/// Gets the nonce of the message sent with the given counter of the message
+/// portal, which stores it as a big-endian uint256
+pub fn portal_nonce(counter: u64) -> Nonce {
+    let mut nonce = [0u8; 32];
+    nonce[24..].copy_from_slice(&counter.to_be_bytes());
+    Nonce::from(nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_message() -> BridgeMessage {
+        let mut data = vec![7u8; 32];
+        data.extend_from_slice(&[1, 2, 3]);
+        BridgeMessage::to_contract(Address::from([1u8; 32]), portal_nonce(5), 100, data)
+    }
+
+    #[test]
+    fn message_id_matches_fuels() {
+        let message = contract_message();
+        let fuels_message = Message::from(message.clone());
+
+        assert_eq!(message.message_id(), fuels_message.message_id());
+        assert_eq!(BridgeMessage::from(fuels_message), message);
+    }
+
+    #[test]
+    fn recognizes_contract_messages() {
+        let message = contract_message();
+        assert!(message.is_contract_message());
+        assert_eq!(message.contract_id(), Some(ContractId::from([7u8; 32])));
+        assert!(matches!(
+            message.to_input(),
+            Input::ResourcePredicate { .. }
+        ));
+
+        let message = BridgeMessage {
+            recipient: Address::from([2u8; 32]),
+            ..contract_message()
+        };
+        assert!(!message.is_contract_message());
+        assert_eq!(message.contract_id(), None);
+        assert!(matches!(message.to_input(), Input::ResourceSigned { .. }));
+    }
+
+    #[test]
+    fn recognizes_every_predicate_version() {
+        for version in versions::VERSIONS {
+            let message = BridgeMessage {
+                recipient: version.predicate_owner(),
+                ..contract_message()
+            };
+            assert!(message.is_contract_message(), "version {}", version.version);
+            assert_eq!(message.contract_id(), Some(ContractId::from([7u8; 32])));
+            assert!(matches!(
+                message.to_input(),
+                Input::ResourcePredicate { code, .. } if code == version.predicate
+            ));
+        }
+    }
+
+    #[test]
+    fn encodes_portal_nonce_as_uint256() {
+        let nonce = portal_nonce(0x0102);
+
+        assert_eq!(nonce[30..], [0x01, 0x02]);
+        assert!(nonce[..30].iter().all(|byte| *byte == 0));
+    }
+}
@@ -0,0 +1,405 @@
+use std::fmt;
+
+use fuel_tx::{ContractId, PanicReason, Receipt, ScriptExecutionResult, Word};
+use fuel_vm::checked_transaction::CheckError;
+use fuels::types::errors::{transaction::Reason, Error};
+
+use crate::{
+    codegen::script_asm::{RELAY_DELIVERED, RELAY_TARGET_LOG_ID},
+    evaluate::EvaluateError,
+};
+
+/// Reason the execution of a relay transaction stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelayFailure {
+    Panic(PanicReason),
+    Revert(Word),
+}
+
+impl fmt::Display for RelayFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(reason) => write!(f, "panic {reason:?}"),
+            Self::Revert(code) => write!(f, "revert {code:#x}"),
+        }
+    }
+}
+
+/// What happened to a relayed message, decoded from the transaction receipts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelayOutcome {
+    /// The receiving contract processed the message
+    Delivered { contract_id: Option<ContractId> },
+    /// The receiving contract panicked or reverted while processing the message
+    ReceiverReverted {
+        contract_id: ContractId,
+        reason: RelayFailure,
+    },
+    /// The transaction ran out of gas, either in the script or in the receiver
+    OutOfGas,
+    /// The script failed before the receiver got the message, e.g. because the
+    /// receiving contract is not among the inputs
+    ScriptFailed {
+        contract_id: Option<ContractId>,
+        reason: RelayFailure,
+    },
+    /// The predicate rejected the transaction, so it was never executed
+    PredicateRejected,
+    /// The receipts don't show how the relay ended, e.g. because the script
+    /// result is missing or the reporting script didn't return `RELAY_DELIVERED`
+    Unknown,
+}
+
+impl RelayOutcome {
+    /// Classifies the receipts of an executed relay transaction. The receiving
+    /// contract is found in the log of [`crate::RelayScript::reporting`], or in
+    /// the call made by the script for other relay scripts.
+    ///
+    /// A message only counts as delivered if the script succeeded and, for the
+    /// reporting script, returned [`RELAY_DELIVERED`].
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        let contract_id = target_contract(receipts);
+        let reported = reported_target(receipts).is_some();
+
+        for receipt in receipts {
+            let (id, reason) = match receipt {
+                Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::OutOfGas => {
+                    return Self::OutOfGas
+                }
+                Receipt::Panic { id, reason, .. } => (id, RelayFailure::Panic(*reason.reason())),
+                Receipt::Revert { id, ra, .. } => (id, RelayFailure::Revert(*ra)),
+                _ => continue,
+            };
+
+            // failures raised inside a contract come from the receiver
+            if *id != ContractId::zeroed() {
+                return Self::ReceiverReverted {
+                    contract_id: *id,
+                    reason,
+                };
+            }
+            return Self::ScriptFailed {
+                contract_id,
+                reason,
+            };
+        }
+
+        let succeeded = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::ScriptResult {
+                    result: ScriptExecutionResult::Success,
+                    ..
+                }
+            )
+        });
+        let returned_delivered = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::Return { id, val, .. }
+                    if *id == ContractId::zeroed() && *val == Word::from(RELAY_DELIVERED)
+            )
+        });
+
+        if succeeded && (!reported || returned_delivered) {
+            Self::Delivered { contract_id }
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Classifies the error returned when executing a relay transaction, if it
+    /// reverted.
+    ///
+    /// fuels only keeps the rendering of validation errors, so a rejection by
+    /// the predicate is classified with [`Self::from_check_error`] on the error
+    /// of checking the transaction before submitting it.
+    pub fn from_error(error: &Error) -> Option<Self> {
+        match error {
+            Error::Transaction(Reason::Reverted { receipts, .. }) => {
+                Some(Self::from_receipts(receipts))
+            }
+            _ => None,
+        }
+    }
+
+    /// Classifies the error of checking a relay transaction locally, if the
+    /// predicate rejected it
+    pub fn from_check_error(error: &CheckError) -> Option<Self> {
+        match error {
+            CheckError::PredicateVerificationFailed(_) => Some(Self::PredicateRejected),
+            _ => None,
+        }
+    }
+
+    /// Classifies the error of [`crate::evaluate::evaluate_predicates`], if the
+    /// predicate rejected the transaction
+    pub fn from_evaluate_error(error: &EvaluateError) -> Option<Self> {
+        match error {
+            EvaluateError::PredicateFailed(_) => Some(Self::PredicateRejected),
+            EvaluateError::InvalidTransaction(_) => None,
+        }
+    }
+
+    /// Checks if the message was delivered
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, Self::Delivered { .. })
+    }
+}
+
+impl fmt::Display for RelayOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delivered { .. } => write!(f, "delivered"),
+            Self::ReceiverReverted {
+                contract_id,
+                reason,
+            } => write!(f, "receiver {contract_id} reverted: {reason}"),
+            Self::OutOfGas => write!(f, "out of gas"),
+            Self::ScriptFailed { reason, .. } => write!(f, "relay script failed: {reason}"),
+            Self::PredicateRejected => write!(f, "predicate rejected"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+// Gets the contract the relay script called or was about to call
+fn target_contract(receipts: &[Receipt]) -> Option<ContractId> {
+    reported_target(receipts).or_else(|| {
+        receipts.iter().find_map(|receipt| match receipt {
+            Receipt::Call { id, to, .. } if *id == ContractId::zeroed() => Some(*to),
+            _ => None,
+        })
+    })
+}
+
+// Gets the contract logged by the reporting script
+fn reported_target(receipts: &[Receipt]) -> Option<ContractId> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::LogData {
+            id,
+            ra,
+            data: Some(data),
+            ..
+        } if *id == ContractId::zeroed() && *ra == Word::from(RELAY_TARGET_LOG_ID) => {
+            <[u8; 32]>::try_from(data.as_slice())
+                .ok()
+                .map(ContractId::from)
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{AssetId, PanicInstruction};
+    use fuel_vm::error::PredicateVerificationFailed;
+
+    use super::*;
+
+    const RECEIVER: ContractId = ContractId::new([7u8; 32]);
+
+    fn target_log() -> Receipt {
+        Receipt::log_data(
+            ContractId::zeroed(),
+            RELAY_TARGET_LOG_ID.into(),
+            0,
+            0,
+            0,
+            0,
+            RECEIVER.to_vec(),
+        )
+    }
+
+    fn call() -> Receipt {
+        Receipt::call(
+            ContractId::zeroed(),
+            RECEIVER,
+            0,
+            AssetId::zeroed(),
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+    }
+
+    fn panic(id: ContractId, reason: PanicReason) -> Receipt {
+        Receipt::panic(id, PanicInstruction::error(reason, 0), 0, 0)
+    }
+
+    fn script_return(val: Word) -> Receipt {
+        Receipt::ret(ContractId::zeroed(), val, 0, 0)
+    }
+
+    #[test]
+    fn decodes_delivered_message() {
+        let receipts = [
+            target_log(),
+            call(),
+            Receipt::ret(RECEIVER, 1, 0, 0),
+            script_return(RELAY_DELIVERED.into()),
+            Receipt::script_result(ScriptExecutionResult::Success, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::Delivered {
+                contract_id: Some(RECEIVER)
+            }
+        );
+
+        // without the log of the reporting script
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts[1..]),
+            RelayOutcome::Delivered {
+                contract_id: Some(RECEIVER)
+            }
+        );
+    }
+
+    #[test]
+    fn requires_successful_script() {
+        assert_eq!(RelayOutcome::from_receipts(&[]), RelayOutcome::Unknown);
+
+        // the reporting script didn't return RELAY_DELIVERED
+        let receipts = [
+            target_log(),
+            call(),
+            Receipt::ret(RECEIVER, 1, 0, 0),
+            script_return(0),
+            Receipt::script_result(ScriptExecutionResult::Success, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::Unknown
+        );
+
+        // no script result
+        let receipts = [call(), Receipt::ret(RECEIVER, 1, 0, 0), script_return(0)];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::Unknown
+        );
+
+        let receipts = [
+            call(),
+            Receipt::ret(RECEIVER, 1, 0, 0),
+            script_return(0),
+            Receipt::script_result(ScriptExecutionResult::GenericFailure(1), 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::Unknown
+        );
+    }
+
+    #[test]
+    fn decodes_receiver_failures() {
+        let receipts = [
+            target_log(),
+            call(),
+            Receipt::revert(RECEIVER, 42, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Revert, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::ReceiverReverted {
+                contract_id: RECEIVER,
+                reason: RelayFailure::Revert(42)
+            }
+        );
+
+        let receipts = [
+            target_log(),
+            call(),
+            panic(RECEIVER, PanicReason::ArithmeticError),
+            Receipt::script_result(ScriptExecutionResult::Panic, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::ReceiverReverted {
+                contract_id: RECEIVER,
+                reason: RelayFailure::Panic(PanicReason::ArithmeticError)
+            }
+        );
+
+        let receipts = [
+            target_log(),
+            call(),
+            panic(RECEIVER, PanicReason::OutOfGas),
+            Receipt::script_result(ScriptExecutionResult::Panic, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::OutOfGas
+        );
+    }
+
+    #[test]
+    fn decodes_script_failures() {
+        let receipts = [
+            target_log(),
+            panic(ContractId::zeroed(), PanicReason::ContractNotInInputs),
+            Receipt::script_result(ScriptExecutionResult::Panic, 0),
+        ];
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::ScriptFailed {
+                contract_id: Some(RECEIVER),
+                reason: RelayFailure::Panic(PanicReason::ContractNotInInputs)
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_predicate_rejection() {
+        let rejection = CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::Panic(PanicReason::PredicateReturnedNonOne),
+        );
+        assert_eq!(
+            RelayOutcome::from_check_error(&rejection),
+            Some(RelayOutcome::PredicateRejected)
+        );
+        assert_eq!(
+            RelayOutcome::from_evaluate_error(&rejection.into()),
+            Some(RelayOutcome::PredicateRejected)
+        );
+
+        let malformed = CheckError::Validity(fuel_tx::ValidityError::NoSpendableInput);
+        assert_eq!(RelayOutcome::from_check_error(&malformed), None);
+        assert_eq!(RelayOutcome::from_evaluate_error(&malformed.into()), None);
+    }
+
+    #[test]
+    fn decodes_submission_errors() {
+        let receipts = vec![
+            call(),
+            Receipt::revert(RECEIVER, 42, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Revert, 0),
+        ];
+        let reverted = Error::Transaction(Reason::Reverted {
+            reason: "Revert(42)".to_string(),
+            revert_id: 42,
+            receipts,
+        });
+        assert_eq!(
+            RelayOutcome::from_error(&reverted),
+            Some(RelayOutcome::ReceiverReverted {
+                contract_id: RECEIVER,
+                reason: RelayFailure::Revert(42)
+            })
+        );
+
+        // validation errors are only kept as text, so they're left to
+        // `from_check_error`
+        let rejection = CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::Panic(PanicReason::PredicateReturnedNonOne),
+        );
+        assert_eq!(RelayOutcome::from_error(&rejection.into()), None);
+        assert_eq!(
+            RelayOutcome::from_error(&Error::Provider("offline".to_string())),
+            None
+        );
+    }
+}
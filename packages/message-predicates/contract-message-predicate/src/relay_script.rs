@@ -0,0 +1,225 @@
+use fuel_tx::Input;
+
+use crate::codegen::{self, predicate_asm, script_asm};
+
+const PROCESS_MESSAGE_FUNCTION_SIGNATURE: &str = "process_message";
+
+/// Builds a message-to-contract script that calls a configurable function on
+/// the contract designated by the message data, together with the predicate
+/// that only accepts that exact script.
+///
+/// The default builder produces the same script and predicate as
+/// [`crate::script_bytecode`] and [`crate::predicate_bytecode`], that is,
+/// `process_message(msg_idx = 0)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayScriptBuilder {
+    fn_name: String,
+    args: Vec<u8>,
+}
+
+impl Default for RelayScriptBuilder {
+    fn default() -> Self {
+        Self {
+            fn_name: PROCESS_MESSAGE_FUNCTION_SIGNATURE.to_string(),
+            args: 0u64.to_be_bytes().to_vec(),
+        }
+    }
+}
+
+impl RelayScriptBuilder {
+    /// Creates a builder for a script calling `fn_name` without arguments
+    pub fn new(fn_name: impl Into<String>) -> Self {
+        Self {
+            fn_name: fn_name.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the function that will be called on the target contract
+    pub fn with_fn_name(mut self, fn_name: impl Into<String>) -> Self {
+        self.fn_name = fn_name.into();
+        self
+    }
+
+    /// Sets the already encoded arguments embedded at the tail of the script,
+    /// e.g. `fuels::core::codec::ABIEncoder` output for `(msg_idx, flags)`
+    pub fn with_args(mut self, args: impl Into<Vec<u8>>) -> Self {
+        self.args = args.into();
+        self
+    }
+
+    /// Generates the script and its matching predicate
+    ///
+    /// # Panics
+    ///
+    /// If the function name is too long to be referenced from the script
+    pub fn build(&self) -> RelayScript {
+        let script = script_asm::bytecode_with_selector(&self.fn_name, &self.args);
+        RelayScript::pinned(script, predicate_asm::bytecode_for_script_hash)
+    }
+}
+
+/// A message-to-contract script and the predicate that pins it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayScript {
+    pub script: Vec<u8>,
+    pub script_hash: [u8; 32],
+    pub predicate: Vec<u8>,
+}
+
+impl RelayScript {
+    /// Script and predicate pair that relays the `InputMessage` with data found at
+    /// any input index, passing that index to `process_message`. The predicate
+    /// rejects transactions with more than one `InputMessage` with data.
+    pub fn any_input() -> Self {
+        Self::pinned(
+            script_asm::any_input_bytecode(),
+            predicate_asm::any_input_bytecode_for_script_hash,
+        )
+    }
+
+    /// Script and predicate pair that forwards the message amount of base asset
+    /// to the target contract when calling `process_message`, so that L1 senders
+    /// can attach ETH to contract-bound messages
+    pub fn value_forwarding() -> Self {
+        Self::pinned(
+            script_asm::value_forwarding_bytecode(),
+            predicate_asm::bytecode_for_script_hash,
+        )
+    }
+
+    /// Script and predicate pair that reimburses the relayer from the message
+    /// value. The script forwards the message amount of base asset to the target
+    /// contract minus the relayer fee, a big-endian u64 in the last
+    /// [`script_asm::RELAYER_FEE_LEN`] bytes of the message data, capped at the
+    /// message amount. The fee is left to the change outputs of the relayer. A
+    /// single predicate root covers every fee, since the message id commits to it.
+    pub fn fee_capped() -> Self {
+        Self::pinned(
+            script_asm::fee_capped_bytecode(),
+            predicate_asm::bytecode_for_script_hash,
+        )
+    }
+
+    /// Script and predicate pair that calls `process_message` on the target
+    /// contract of every `InputMessage` with data in the transaction, so a
+    /// relayer can deliver several messages at once. The predicate only pins
+    /// the script, since the script itself processes every message with data.
+    pub fn batch() -> Self {
+        Self::pinned(
+            script_asm::batch_bytecode(),
+            predicate_asm::script_only_bytecode_for_script_hash,
+        )
+    }
+
+    /// Script and predicate pair that relays the message like the default
+    /// script, but logs the target contract before calling it and returns
+    /// [`script_asm::RELAY_DELIVERED`] after, so that receipts can be decoded
+    /// with [`crate::outcome::RelayOutcome`]
+    pub fn reporting() -> Self {
+        Self::pinned(
+            script_asm::reporting_bytecode(),
+            predicate_asm::bytecode_for_script_hash,
+        )
+    }
+
+    // Pairs the script with the predicate generated for its hash
+    fn pinned(script: Vec<u8>, predicate_for_script_hash: fn([u8; 32]) -> Vec<u8>) -> Self {
+        let script_hash = codegen::script_hash(&script);
+        let predicate = predicate_for_script_hash(script_hash);
+
+        Self {
+            script,
+            script_hash,
+            predicate,
+        }
+    }
+
+    /// Gets the root of the predicate, i.e. the recipient L1 messages must be sent to
+    pub fn predicate_root(&self) -> [u8; 32] {
+        Input::predicate_owner(&self.predicate).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_snapshots() {
+        let relay = RelayScriptBuilder::default().build();
+
+        assert_eq!(relay.script, crate::script_bytecode());
+        assert_eq!(relay.script_hash, crate::script_hash());
+        assert_eq!(relay.predicate, crate::predicate_bytecode());
+        assert_eq!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn custom_selector_and_args() {
+        let mut args = 3u64.to_be_bytes().to_vec();
+        args.extend_from_slice(&7u64.to_be_bytes());
+        let relay = RelayScriptBuilder::new("process_message_v2")
+            .with_args(args.clone())
+            .build();
+
+        let mut tail = fuels::core::codec::encode_fn_selector("process_message_v2");
+        tail.extend_from_slice(&args);
+        assert!(relay.script.ends_with(&tail));
+
+        // the predicate embeds the hash of the custom script
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn any_input_pins_its_own_script() {
+        let relay = RelayScript::any_input();
+
+        assert_eq!(relay.predicate, predicate_asm::any_input_bytecode());
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn value_forwarding_pins_its_own_script() {
+        let relay = RelayScript::value_forwarding();
+
+        assert_eq!(relay.predicate, predicate_asm::value_forwarding_bytecode());
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn reporting_pins_its_own_script() {
+        let relay = RelayScript::reporting();
+
+        assert_eq!(relay.predicate, predicate_asm::reporting_bytecode());
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn fee_capped_pins_its_own_script() {
+        let relay = RelayScript::fee_capped();
+
+        assert_eq!(relay.predicate, predicate_asm::fee_capped_bytecode());
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+
+    #[test]
+    fn batch_pins_its_own_script() {
+        let relay = RelayScript::batch();
+
+        assert_eq!(relay.predicate, predicate_asm::batch_bytecode());
+        assert!(relay.predicate.ends_with(&relay.script_hash));
+        assert_ne!(relay.script_hash, crate::script_hash());
+        assert_ne!(relay.predicate_root(), crate::predicate_root());
+    }
+}
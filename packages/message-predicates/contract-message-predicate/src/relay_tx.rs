@@ -0,0 +1,569 @@
+use std::fmt;
+
+use fuel_tx::{
+    Address, AssetId, Bytes32, ContractId, Output, PanicReason, Receipt, TxPointer, UtxoId,
+};
+use fuels::{
+    accounts::wallet::WalletUnlocked,
+    prelude::{ScriptTransaction, TxPolicies},
+    types::{
+        coin::Coin,
+        coin_type::CoinType,
+        errors::{error, Error, Result},
+        input::Input,
+        transaction::Transaction,
+        transaction_builders::{
+            create_coin_message_predicate, create_coin_predicate, BuildableTransaction,
+            ScriptTransactionBuilder, TransactionBuilder,
+        },
+    },
+};
+
+/// Builds transactions relaying messages to contracts through the
+/// message-to-contract script.
+///
+/// Inputs are laid out as the message first, then the target contracts, any
+/// additional inputs and finally the gas coins. Every contract input gets a
+/// contract output pointing at its index, and unspent coins are returned with a
+/// single change output per asset.
+#[derive(Debug, Clone)]
+pub struct RelayTxBuilder {
+    script: Vec<u8>,
+    inputs: Vec<Input>,
+    gas_coins: Vec<Input>,
+    outputs: Vec<Output>,
+    variable_outputs: usize,
+    change_to: Option<Address>,
+    tx_policies: TxPolicies,
+}
+
+impl RelayTxBuilder {
+    /// Creates a builder relaying the given message with the default script
+    pub fn new(message: Input) -> Self {
+        Self::from_inputs(vec![message])
+    }
+
+    /// Creates a builder keeping the given inputs in order, for scripts that
+    /// find the message at any input index, e.g. [`crate::RelayScript::any_input`]
+    pub fn from_inputs(inputs: Vec<Input>) -> Self {
+        Self {
+            script: crate::script_bytecode(),
+            inputs,
+            gas_coins: Vec::new(),
+            outputs: Vec::new(),
+            variable_outputs: 1,
+            change_to: None,
+            tx_policies: TxPolicies::default(),
+        }
+    }
+
+    /// Sets the script run by the transaction, the default relay script otherwise
+    pub fn with_script(mut self, script: Vec<u8>) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Appends the contracts that the relayed message interacts with
+    pub fn with_contracts(mut self, contracts: impl IntoIterator<Item = Input>) -> Self {
+        self.inputs.extend(contracts);
+        self
+    }
+
+    /// Appends additional inputs, e.g. other messages or predicate coins
+    pub fn with_inputs(mut self, inputs: impl IntoIterator<Item = Input>) -> Self {
+        self.inputs.extend(inputs);
+        self
+    }
+
+    /// Sets the coins paying for the transaction, signed by the building wallet
+    pub fn with_gas_coins(mut self, coins: &[Coin]) -> Self {
+        self.gas_coins = coins
+            .iter()
+            .map(|coin| Input::resource_signed(CoinType::Coin(coin.clone())))
+            .collect();
+        self
+    }
+
+    /// Appends outputs after the contract and change outputs
+    pub fn with_outputs(mut self, outputs: impl IntoIterator<Item = Output>) -> Self {
+        self.outputs.extend(outputs);
+        self
+    }
+
+    /// Sets the number of variable outputs, used by the receiving contract to
+    /// transfer assets. Defaults to one.
+    pub fn with_variable_outputs(mut self, variable_outputs: usize) -> Self {
+        self.variable_outputs = variable_outputs;
+        self
+    }
+
+    /// Sets the recipient of unspent coins, the building wallet otherwise
+    pub fn with_change_to(mut self, change_to: Address) -> Self {
+        self.change_to = Some(change_to);
+        self
+    }
+
+    /// Sets the transaction policies, e.g. the tip and script gas limit
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    /// Gets the inputs of the transaction, in order
+    pub fn inputs(&self) -> Vec<Input> {
+        [self.inputs.as_slice(), self.gas_coins.as_slice()].concat()
+    }
+
+    /// Gets the outputs of the transaction, in order
+    pub fn outputs(&self, change_to: Address, base_asset_id: AssetId) -> Vec<Output> {
+        let inputs = self.inputs();
+        let mut outputs = contract_outputs(&inputs);
+
+        let mut change_assets = vec![base_asset_id];
+        for input in &inputs {
+            if let Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } =
+                input
+            {
+                let asset_id = resource.coin_asset_id().unwrap_or(base_asset_id);
+                if !change_assets.contains(&asset_id) {
+                    change_assets.push(asset_id);
+                }
+            }
+        }
+        outputs.extend(
+            change_assets
+                .into_iter()
+                .map(|asset_id| Output::change(change_to, 0, asset_id)),
+        );
+
+        outputs.extend(self.outputs.iter().cloned());
+        outputs.extend(
+            (0..self.variable_outputs)
+                .map(|_| Output::variable(Address::zeroed(), 0, AssetId::zeroed())),
+        );
+        outputs
+    }
+
+    /// Builds and signs the transaction with the given wallet
+    pub async fn build(self, wallet: &WalletUnlocked) -> Result<ScriptTransaction> {
+        let provider = wallet
+            .provider()
+            .ok_or_else(|| error!(Other, "wallet has no provider"))?;
+        let change_to = self.change_to.unwrap_or_else(|| wallet.address().into());
+        let outputs = self.outputs(change_to, *provider.base_asset_id());
+
+        let mut builder = ScriptTransactionBuilder::default()
+            .with_inputs(self.inputs())
+            .with_outputs(outputs)
+            .with_tx_policies(self.tx_policies)
+            .with_script(self.script);
+        builder.add_signer(wallet.clone())?;
+
+        builder.build(provider).await
+    }
+
+    /// Builds the transaction without signing it, for relayers that add their
+    /// own gas coins and signatures. Fails if any input has to be signed.
+    pub fn build_unsigned(&self, base_asset_id: AssetId) -> Result<fuel_tx::Script> {
+        let change_to = self.change_to.unwrap_or_default();
+        let mut builder = fuel_tx::TransactionBuilder::script(self.script.clone(), vec![]);
+        if let Some(script_gas_limit) = self.tx_policies.script_gas_limit() {
+            builder.script_gas_limit(script_gas_limit);
+        }
+        if let Some(tip) = self.tx_policies.tip() {
+            builder.tip(tip);
+        }
+        if let Some(max_fee) = self.tx_policies.max_fee() {
+            builder.max_fee_limit(max_fee);
+        }
+
+        for (index, input) in self.inputs().into_iter().enumerate() {
+            let input = match input {
+                Input::ResourcePredicate {
+                    resource: CoinType::Message(message),
+                    code,
+                    data,
+                } => create_coin_message_predicate(message, code, data),
+                Input::ResourcePredicate {
+                    resource: CoinType::Coin(coin),
+                    code,
+                    data,
+                } => create_coin_predicate(coin.asset_id, coin, code, data),
+                Input::Contract {
+                    utxo_id,
+                    balance_root,
+                    state_root,
+                    tx_pointer,
+                    contract_id,
+                } => fuel_tx::Input::contract(
+                    utxo_id,
+                    balance_root,
+                    state_root,
+                    tx_pointer,
+                    contract_id,
+                ),
+                Input::ResourceSigned { .. } => {
+                    return Err(error!(Other, "input {index} has to be signed"))
+                }
+            };
+            builder.add_input(input);
+        }
+        for output in self.outputs(change_to, base_asset_id) {
+            builder.add_output(output);
+        }
+
+        Ok(builder.finalize_without_signature_inner())
+    }
+
+    /// Finds the contracts the transaction needs by dry-running it and adding a
+    /// contract input for the contract reported by each `ContractNotInInputs`
+    /// panic, one round at a time, until the execution no longer asks for one.
+    ///
+    /// Returns the added contracts in discovery order, or an error if they were
+    /// not all found within `max_rounds` dry-runs.
+    pub async fn discover_contracts(
+        &mut self,
+        wallet: &WalletUnlocked,
+        max_rounds: usize,
+    ) -> std::result::Result<Vec<ContractId>, ContractDiscoveryError> {
+        let provider = wallet
+            .provider()
+            .ok_or_else(|| error!(Other, "wallet has no provider"))?;
+
+        let mut discovered = Vec::new();
+        for _ in 0..max_rounds {
+            let tx = self.clone().build(wallet).await?;
+            let receipts = provider.dry_run_opt(tx, false, None).await?.take_receipts();
+
+            match missing_contract(&receipts) {
+                Some(contract_id) => {
+                    self.inputs.push(contract_input(contract_id));
+                    discovered.push(contract_id);
+                }
+                None => return Ok(discovered),
+            }
+        }
+
+        Err(ContractDiscoveryError::TooManyRounds {
+            max_rounds,
+            discovered,
+        })
+    }
+
+    /// Estimates the script gas limit and max fee of the transaction by
+    /// dry-running it at the current gas price.
+    ///
+    /// `margin_percent` is added on top of both the gas used by the script and
+    /// the gas price, so the transaction still goes through if the receiving
+    /// contract or the gas price change slightly before it is included.
+    pub async fn estimate_fees(
+        &self,
+        wallet: &WalletUnlocked,
+        margin_percent: u64,
+    ) -> Result<RelayFeeEstimate> {
+        let provider = wallet
+            .provider()
+            .ok_or_else(|| error!(Other, "wallet has no provider"))?;
+        let params = provider.consensus_parameters();
+        let dry_run_policies = self.tx_policies.with_max_fee(0);
+
+        // give the script all the gas the transaction can afford
+        let probe = self
+            .clone()
+            .with_tx_policies(dry_run_policies.with_script_gas_limit(0))
+            .build(wallet)
+            .await?;
+        let available_gas = params
+            .tx_params()
+            .max_gas_per_tx()
+            .saturating_sub(probe.max_gas(params));
+        let tx = self
+            .clone()
+            .with_tx_policies(dry_run_policies.with_script_gas_limit(available_gas))
+            .build(wallet)
+            .await?;
+
+        let receipts = provider
+            .dry_run_opt(tx, false, Some(0))
+            .await?
+            .take_receipts_checked(None)?;
+        let gas_used = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .ok_or_else(|| error!(Other, "dry-run returned no script result"))?;
+
+        let gas_price = provider.latest_gas_price().await?.gas_price;
+        let script_gas_limit = with_margin(gas_used, margin_percent);
+        let tx = self
+            .clone()
+            .with_tx_policies(dry_run_policies.with_script_gas_limit(script_gas_limit))
+            .build(wallet)
+            .await?;
+        let max_fee = tx
+            .fee_checked_from_tx(params, with_margin(gas_price, margin_percent))
+            .ok_or_else(|| error!(Other, "fee calculation overflowed"))?
+            .max_fee();
+
+        Ok(RelayFeeEstimate {
+            gas_price,
+            script_gas_limit,
+            max_fee,
+        })
+    }
+}
+
+/// Script gas limit and max fee to relay a message with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayFeeEstimate {
+    /// Gas price at the time of the estimation, without margin
+    pub gas_price: u64,
+    pub script_gas_limit: u64,
+    pub max_fee: u64,
+}
+
+impl RelayFeeEstimate {
+    /// Sets the estimated script gas limit and max fee on the given policies
+    pub fn apply(&self, tx_policies: TxPolicies) -> TxPolicies {
+        tx_policies
+            .with_script_gas_limit(self.script_gas_limit)
+            .with_max_fee(self.max_fee)
+    }
+}
+
+// Adds the given percentage to the value, rounding up
+fn with_margin(value: u64, margin_percent: u64) -> u64 {
+    let value = u128::from(value) * u128::from(100 + margin_percent);
+    u64::try_from(value.div_ceil(100)).unwrap_or(u64::MAX)
+}
+
+/// Error raised while discovering the contracts a relay transaction needs
+#[derive(Debug)]
+pub enum ContractDiscoveryError {
+    /// The transaction could not be built or dry-run
+    Provider(Error),
+    /// The execution still asked for contracts after the last round
+    TooManyRounds {
+        max_rounds: usize,
+        discovered: Vec<ContractId>,
+    },
+}
+
+impl fmt::Display for ContractDiscoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Provider(e) => write!(f, "failed to dry-run relay transaction: {e}"),
+            Self::TooManyRounds {
+                max_rounds,
+                discovered,
+            } => write!(
+                f,
+                "contracts still missing after {max_rounds} rounds, discovered {} so far",
+                discovered.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ContractDiscoveryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Provider(e) => Some(e),
+            Self::TooManyRounds { .. } => None,
+        }
+    }
+}
+
+impl From<Error> for ContractDiscoveryError {
+    fn from(e: Error) -> Self {
+        Self::Provider(e)
+    }
+}
+
+/// Gets the contract an execution panicked on for not being in the inputs
+pub fn missing_contract(receipts: &[Receipt]) -> Option<ContractId> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic {
+            reason,
+            contract_id: Some(contract_id),
+            ..
+        } if *reason.reason() == PanicReason::ContractNotInInputs => Some(*contract_id),
+        _ => None,
+    })
+}
+
+/// Gets a contract input for the given contract
+pub fn contract_input(contract_id: ContractId) -> Input {
+    Input::Contract {
+        utxo_id: UtxoId::default(),
+        balance_root: Bytes32::zeroed(),
+        state_root: Bytes32::zeroed(),
+        tx_pointer: TxPointer::default(),
+        contract_id,
+    }
+}
+
+/// Gets a contract output for every contract input, pointing at its index
+pub fn contract_outputs(inputs: &[Input]) -> Vec<Output> {
+    inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| matches!(input, Input::Contract { .. }))
+        .map(|(index, _)| Output::contract(index as u16, Bytes32::zeroed(), Bytes32::zeroed()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{
+        field::{Inputs, Outputs, ScriptGasLimit},
+        PanicInstruction,
+    };
+    use fuels::types::message::{Message, MessageStatus};
+
+    use super::*;
+
+    fn contract(id: u8) -> Input {
+        contract_input(ContractId::from([id; 32]))
+    }
+
+    fn message() -> Input {
+        Input::ResourcePredicate {
+            resource: CoinType::Message(Message {
+                amount: 100,
+                sender: Default::default(),
+                recipient: Default::default(),
+                nonce: Default::default(),
+                data: vec![1; 32],
+                da_height: 0,
+                status: MessageStatus::Unspent,
+            }),
+            code: crate::predicate_bytecode(),
+            data: vec![],
+        }
+    }
+
+    fn coin(asset_id: AssetId) -> Coin {
+        Coin {
+            asset_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn indexes_contract_outputs() {
+        let builder = RelayTxBuilder::new(message())
+            .with_contracts([contract(1), contract(2), contract(3)])
+            .with_gas_coins(&[coin(AssetId::BASE)]);
+
+        let outputs = builder.outputs(Address::zeroed(), AssetId::BASE);
+        let indexes: Vec<u16> = outputs
+            .iter()
+            .filter_map(|output| match output {
+                Output::Contract(contract) => Some(contract.input_index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(indexes, vec![1, 2, 3]);
+        assert_eq!(builder.inputs().len(), 5);
+    }
+
+    #[test]
+    fn keeps_given_input_order() {
+        let builder = RelayTxBuilder::from_inputs(vec![contract(1), message(), contract(2)]);
+
+        let outputs = contract_outputs(&builder.inputs());
+        assert_eq!(
+            outputs,
+            vec![
+                Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()),
+                Output::contract(2, Bytes32::zeroed(), Bytes32::zeroed()),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_change_output_per_asset() {
+        let other_asset = AssetId::from([7; 32]);
+        let builder = RelayTxBuilder::new(message())
+            .with_contracts([contract(1)])
+            .with_gas_coins(&[coin(AssetId::BASE), coin(AssetId::BASE), coin(other_asset)])
+            .with_variable_outputs(2);
+
+        let outputs = builder.outputs(Address::zeroed(), AssetId::BASE);
+        let change_assets: Vec<AssetId> = outputs
+            .iter()
+            .filter_map(|output| match output {
+                Output::Change { asset_id, .. } => Some(*asset_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(change_assets, vec![AssetId::BASE, other_asset]);
+        assert_eq!(
+            outputs
+                .iter()
+                .filter(|output| matches!(output, Output::Variable { .. }))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn builds_unsigned_relay() {
+        let tx = RelayTxBuilder::new(message())
+            .with_contracts([contract(1), contract(2)])
+            .with_tx_policies(TxPolicies::default().with_script_gas_limit(1000))
+            .build_unsigned(AssetId::BASE)
+            .unwrap();
+
+        assert_eq!(tx.inputs().len(), 3);
+        assert!(tx.inputs()[0].is_message_data_predicate());
+        assert_eq!(
+            tx.outputs()[..2],
+            [
+                Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()),
+                Output::contract(2, Bytes32::zeroed(), Bytes32::zeroed()),
+            ]
+        );
+        assert_eq!(*tx.script_gas_limit(), 1000);
+    }
+
+    #[test]
+    fn unsigned_relay_rejects_signed_inputs() {
+        let builder = RelayTxBuilder::new(message()).with_gas_coins(&[coin(AssetId::BASE)]);
+
+        assert!(builder.build_unsigned(AssetId::BASE).is_err());
+    }
+
+    #[test]
+    fn finds_missing_contract() {
+        let panic = |reason, contract_id| Receipt::Panic {
+            id: ContractId::zeroed(),
+            reason: PanicInstruction::error(reason, 0),
+            pc: 0,
+            is: 0,
+            contract_id,
+        };
+        let missing = ContractId::from([9; 32]);
+
+        assert_eq!(
+            missing_contract(&[panic(PanicReason::ContractNotInInputs, Some(missing))]),
+            Some(missing)
+        );
+        assert_eq!(
+            missing_contract(&[panic(PanicReason::InputNotFound, Some(missing))]),
+            None
+        );
+        assert_eq!(missing_contract(&[]), None);
+    }
+
+    #[test]
+    fn margin_rounds_up() {
+        assert_eq!(with_margin(1000, 0), 1000);
+        assert_eq!(with_margin(1000, 20), 1200);
+        assert_eq!(with_margin(1001, 10), 1102);
+        assert_eq!(with_margin(u64::MAX, 50), u64::MAX);
+    }
+}
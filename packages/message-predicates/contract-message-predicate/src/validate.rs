@@ -0,0 +1,298 @@
+use std::fmt;
+
+use fuel_tx::{Address, Input, Output};
+use fuels::{prelude::ScriptTransaction, types::transaction::Transaction};
+
+use crate::{
+    codegen, predicate_root, predicate_v2_root, script_hash, PREDICATE_BYTECODE,
+    PREDICATE_V2_BYTECODE,
+};
+
+/// Reason a relay transaction would be rejected or fail to relay its message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayTxError {
+    /// The script is not the message-to-contract script pinned by the predicate
+    WrongScriptHash {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    /// The first input is not a message with data for the script to relay
+    MissingMessage,
+    /// The relayed message is not spent through a contract message predicate
+    WrongPredicate { index: usize },
+    /// A message spent through the predicate is not owned by the predicate root
+    WrongPredicateOwner {
+        index: usize,
+        expected: Address,
+        actual: Address,
+    },
+    /// A message with data other than the relayed one
+    ExtraMessageData { index: usize },
+    /// A contract input without its contract output
+    MissingContractOutput { input_index: usize },
+    /// A contract output that doesn't point at a contract input
+    InvalidContractOutput {
+        output_index: usize,
+        input_index: usize,
+    },
+}
+
+impl fmt::Display for RelayTxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongScriptHash { expected, actual } => write!(
+                f,
+                "script hash 0x{} does not match 0x{}",
+                hex::encode(actual),
+                hex::encode(expected)
+            ),
+            Self::MissingMessage => write!(f, "input 0 is not a message with data"),
+            Self::WrongPredicate { index } => write!(
+                f,
+                "message at input {index} is not spent through the contract message predicate"
+            ),
+            Self::WrongPredicateOwner {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "message at input {index} is owned by {actual} instead of the predicate root {expected}"
+            ),
+            Self::ExtraMessageData { index } => {
+                write!(f, "input {index} is another message with data")
+            }
+            Self::MissingContractOutput { input_index } => {
+                write!(f, "contract input {input_index} has no contract output")
+            }
+            Self::InvalidContractOutput {
+                output_index,
+                input_index,
+            } => write!(
+                f,
+                "contract output {output_index} points at input {input_index}, which is not a contract"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RelayTxError {}
+
+/// Checks offline that a relay transaction meets the conditions enforced by the
+/// contract message predicate, as well as the input and output layout the
+/// script needs to relay the message
+pub fn validate_relay_tx(tx: &ScriptTransaction) -> Result<(), RelayTxError> {
+    validate(tx.script(), tx.inputs(), tx.outputs())
+}
+
+fn validate(script: &[u8], inputs: &[Input], outputs: &[Output]) -> Result<(), RelayTxError> {
+    let actual = codegen::script_hash(script);
+    if actual != script_hash() {
+        return Err(RelayTxError::WrongScriptHash {
+            expected: script_hash(),
+            actual,
+        });
+    }
+
+    match inputs.first() {
+        Some(Input::MessageDataPredicate(_)) => {}
+        Some(input) if input.is_message() && !input.input_data().unwrap_or_default().is_empty() => {
+            return Err(RelayTxError::WrongPredicate { index: 0 })
+        }
+        _ => return Err(RelayTxError::MissingMessage),
+    }
+
+    for (index, input) in inputs.iter().enumerate() {
+        if !input.is_message() {
+            continue;
+        }
+
+        let expected_owner = match input.input_predicate() {
+            Some(PREDICATE_BYTECODE) => Some(Address::from(predicate_root())),
+            Some(PREDICATE_V2_BYTECODE) => Some(Address::from(predicate_v2_root())),
+            _ => None,
+        };
+        if index == 0 && expected_owner.is_none() {
+            return Err(RelayTxError::WrongPredicate { index });
+        }
+        if index > 0 && !input.input_data().unwrap_or_default().is_empty() {
+            return Err(RelayTxError::ExtraMessageData { index });
+        }
+        if let Some(expected) = expected_owner {
+            let actual = *input.recipient().expect("messages have a recipient");
+            if actual != expected {
+                return Err(RelayTxError::WrongPredicateOwner {
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    for (output_index, output) in outputs.iter().enumerate() {
+        if let Output::Contract(contract) = output {
+            let input_index = usize::from(contract.input_index);
+            if !matches!(inputs.get(input_index), Some(Input::Contract(_))) {
+                return Err(RelayTxError::InvalidContractOutput {
+                    output_index,
+                    input_index,
+                });
+            }
+        }
+    }
+    for (input_index, input) in inputs.iter().enumerate() {
+        let has_output = outputs.iter().any(|output| {
+            matches!(output, Output::Contract(contract) if usize::from(contract.input_index) == input_index)
+        });
+        if matches!(input, Input::Contract(_)) && !has_output {
+            return Err(RelayTxError::MissingContractOutput { input_index });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{AssetId, Bytes32, ContractId, TxPointer, UtxoId};
+    use fuel_vm::fuel_types::Nonce;
+
+    use super::*;
+
+    fn message(index: u8, data: Vec<u8>) -> Input {
+        let predicate = crate::predicate_bytecode();
+        let recipient = Input::predicate_owner(&predicate);
+        let nonce = Nonce::from([index; 32]);
+        if data.is_empty() {
+            Input::message_coin_predicate(
+                Address::zeroed(),
+                recipient,
+                100,
+                nonce,
+                0,
+                predicate,
+                vec![],
+            )
+        } else {
+            Input::message_data_predicate(
+                Address::zeroed(),
+                recipient,
+                100,
+                nonce,
+                0,
+                data,
+                predicate,
+                vec![],
+            )
+        }
+    }
+
+    fn contract() -> Input {
+        Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            ContractId::zeroed(),
+        )
+    }
+
+    fn outputs(contract_index: u16) -> Vec<Output> {
+        vec![
+            Output::contract(contract_index, Bytes32::zeroed(), Bytes32::zeroed()),
+            Output::change(Address::zeroed(), 0, AssetId::BASE),
+        ]
+    }
+
+    #[test]
+    fn accepts_relay_layout() {
+        let inputs = vec![message(0, vec![1; 32]), contract(), message(2, vec![])];
+
+        assert_eq!(
+            validate(&crate::script_bytecode(), &inputs, &outputs(1)),
+            Ok(())
+        );
+
+        // the relayed message can also be spent through the gas-optimized predicate
+        let mut inputs = inputs;
+        if let Input::MessageDataPredicate(message) = &mut inputs[0] {
+            message.predicate = crate::predicate_v2_bytecode().into();
+            message.recipient = Address::from(crate::predicate_v2_root());
+        }
+        assert_eq!(
+            validate(&crate::script_bytecode(), &inputs, &outputs(1)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_script() {
+        let inputs = vec![message(0, vec![1; 32]), contract()];
+
+        assert!(matches!(
+            validate(&[0u8; 4], &inputs, &outputs(1)),
+            Err(RelayTxError::WrongScriptHash { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_message_layouts() {
+        let script = crate::script_bytecode();
+
+        let inputs = vec![contract(), message(1, vec![1; 32])];
+        assert_eq!(
+            validate(&script, &inputs, &outputs(0)),
+            Err(RelayTxError::MissingMessage)
+        );
+
+        let inputs = vec![message(0, vec![1; 32]), contract(), message(2, vec![1; 32])];
+        assert_eq!(
+            validate(&script, &inputs, &outputs(1)),
+            Err(RelayTxError::ExtraMessageData { index: 2 })
+        );
+
+        let mut inputs = vec![message(0, vec![1; 32]), contract()];
+        if let Input::MessageDataPredicate(message) = &mut inputs[0] {
+            message.recipient = Address::zeroed();
+        }
+        assert!(matches!(
+            validate(&script, &inputs, &outputs(1)),
+            Err(RelayTxError::WrongPredicateOwner { index: 0, .. })
+        ));
+
+        let inputs = vec![
+            Input::message_data_signed(
+                Address::zeroed(),
+                Address::zeroed(),
+                100,
+                Nonce::zeroed(),
+                0,
+                vec![1; 32],
+            ),
+            contract(),
+        ];
+        assert_eq!(
+            validate(&script, &inputs, &outputs(1)),
+            Err(RelayTxError::WrongPredicate { index: 0 })
+        );
+    }
+
+    #[test]
+    fn rejects_contract_output_layouts() {
+        let script = crate::script_bytecode();
+        let inputs = vec![message(0, vec![1; 32]), contract()];
+
+        assert_eq!(
+            validate(&script, &inputs, &outputs(0)),
+            Err(RelayTxError::InvalidContractOutput {
+                output_index: 0,
+                input_index: 0
+            })
+        );
+        assert_eq!(
+            validate(&script, &inputs, &outputs(1)[1..]),
+            Err(RelayTxError::MissingContractOutput { input_index: 1 })
+        );
+    }
+}
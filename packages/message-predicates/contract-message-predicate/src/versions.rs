@@ -0,0 +1,333 @@
+use fuel_tx::{Address, Input};
+
+use crate::codegen;
+
+/// A released pair of the message-to-contract script and predicate.
+///
+/// The bytecode of every release is frozen here, so messages sent to the root
+/// of an older predicate can still be spent after the generators change. Every
+/// variant of [`crate::RelayScript`] is released as its own version, so that
+/// messages sent to its root are recognized as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PredicateVersion {
+    pub version: u16,
+    pub script: &'static [u8],
+    pub script_hash: [u8; 32],
+    pub predicate: &'static [u8],
+    pub root: [u8; 32],
+}
+
+impl PredicateVersion {
+    // Gets the root of the predicate as its owner address
+    pub fn predicate_owner(&self) -> Address {
+        Address::from(self.root)
+    }
+
+    // Checks the stored hash and root against the stored bytecode
+    pub fn is_consistent(&self) -> bool {
+        codegen::script_hash(self.script) == self.script_hash
+            && Input::predicate_owner(self.predicate) == self.predicate_owner()
+    }
+}
+
+const V1_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x50, 0x50, 0x45, 0x00, 0x20, 0x61, 0x48, 0x02, 0x48,
+    0x60, 0x45, 0x20, 0x20, 0x50, 0x4c, 0xc0, 0x34, 0x50, 0x51, 0x10, 0x20, 0x5f, 0x51, 0x30, 0x00,
+    0x50, 0x58, 0xc0, 0x4b, 0x50, 0x55, 0x10, 0x28, 0x5f, 0x55, 0x60, 0x00, 0x2d, 0x44, 0x00, 0x0a,
+    0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63,
+    0x65, 0x73, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00,
+];
+
+const V1_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x4c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x5b, 0x50, 0x10, 0x12, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x59, 0x5d, 0x70, 0x01,
+    0x61, 0x61, 0x72, 0x00, 0x5b, 0x61, 0xa0, 0x10, 0x61, 0x65, 0x72, 0x45, 0x5b, 0x64, 0x00, 0x12,
+    0x5b, 0x5c, 0x10, 0x0b, 0x24, 0x04, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x7c, 0xc4, 0x80, 0xc6,
+    0x38, 0x5f, 0xe2, 0xc3, 0x1d, 0xc9, 0x5c, 0xc8, 0x30, 0xe4, 0xff, 0xb7, 0x5d, 0xa5, 0x53, 0x25,
+    0x58, 0xef, 0x93, 0x8b, 0x83, 0x68, 0xda, 0x93, 0x0b, 0xf6, 0x07, 0x22,
+];
+
+pub const V1: PredicateVersion = PredicateVersion {
+    version: 1,
+    script: V1_SCRIPT,
+    script_hash: [
+        0x7c, 0xc4, 0x80, 0xc6, 0x38, 0x5f, 0xe2, 0xc3, 0x1d, 0xc9, 0x5c, 0xc8, 0x30, 0xe4, 0xff,
+        0xb7, 0x5d, 0xa5, 0x53, 0x25, 0x58, 0xef, 0x93, 0x8b, 0x83, 0x68, 0xda, 0x93, 0x0b, 0xf6,
+        0x07, 0x22,
+    ],
+    predicate: V1_PREDICATE,
+    root: [
+        0xe8, 0x21, 0xb9, 0x78, 0xbc, 0xce, 0x9a, 0xbb, 0xf4, 0x0c, 0x3e, 0x50, 0xea, 0x30, 0x14,
+        0x3e, 0x68, 0xc6, 0x5f, 0xa9, 0x5b, 0x9d, 0xa8, 0x90, 0x7f, 0xef, 0x59, 0xc0, 0x2d, 0x95,
+        0x4c, 0xec,
+    ],
+};
+
+const V2_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x4c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x59, 0x5d, 0x70, 0x01, 0x61, 0x61, 0x72, 0x00,
+    0x5b, 0x61, 0xa0, 0x0a, 0x73, 0x5c, 0x00, 0x0f, 0x24, 0x50, 0x00, 0x00, 0x61, 0x65, 0x72, 0x45,
+    0x73, 0x64, 0x00, 0x12, 0x90, 0x00, 0x00, 0x0a, 0x24, 0x00, 0x00, 0x00, 0x7c, 0xc4, 0x80, 0xc6,
+    0x38, 0x5f, 0xe2, 0xc3, 0x1d, 0xc9, 0x5c, 0xc8, 0x30, 0xe4, 0xff, 0xb7, 0x5d, 0xa5, 0x53, 0x25,
+    0x58, 0xef, 0x93, 0x8b, 0x83, 0x68, 0xda, 0x93, 0x0b, 0xf6, 0x07, 0x22,
+];
+
+/// The gas-optimized predicate, pinning the same script as [`V1`]
+pub const V2: PredicateVersion = PredicateVersion {
+    version: 2,
+    script: V1_SCRIPT,
+    script_hash: V1.script_hash,
+    predicate: V2_PREDICATE,
+    root: [
+        0xd4, 0x5c, 0x1c, 0x9e, 0xe5, 0xfc, 0x44, 0xc0, 0xed, 0xa1, 0x4f, 0x2c, 0x8b, 0x05, 0x4e,
+        0xe1, 0x6a, 0x0d, 0xe0, 0xb5, 0x02, 0x49, 0xfa, 0xb7, 0x7f, 0x42, 0x33, 0xea, 0x2f, 0xbe,
+        0x30, 0xcb,
+    ],
+};
+
+const ANY_INPUT_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x58, 0x50, 0x45, 0x00, 0x20, 0x61, 0x5c, 0x00, 0x05,
+    0x72, 0x6c, 0x00, 0x02, 0x1a, 0x60, 0x00, 0x00, 0x61, 0x65, 0x82, 0x00, 0x5b, 0x65, 0xb0, 0x0a,
+    0x61, 0x69, 0x82, 0x45, 0x5b, 0x68, 0x00, 0x0c, 0x50, 0x61, 0x80, 0x01, 0x5b, 0x61, 0x70, 0x06,
+    0x61, 0x49, 0x82, 0x48, 0x60, 0x45, 0x20, 0x20, 0x50, 0x4c, 0xc0, 0x5c, 0x50, 0x51, 0x10, 0x20,
+    0x5f, 0x51, 0x30, 0x00, 0x50, 0x59, 0x10, 0x30, 0x5f, 0x59, 0x80, 0x00, 0x50, 0x55, 0x10, 0x28,
+    0x5f, 0x55, 0x60, 0x00, 0x2d, 0x44, 0x00, 0x0a, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65,
+];
+
+const ANY_INPUT_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x5c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x5b, 0x50, 0x10, 0x16, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x1a, 0x54, 0x00, 0x00,
+    0x59, 0x5d, 0x70, 0x01, 0x61, 0x61, 0x72, 0x00, 0x5b, 0x61, 0xa0, 0x14, 0x61, 0x65, 0x72, 0x45,
+    0x13, 0x6d, 0x90, 0x00, 0x5b, 0x6c, 0x00, 0x14, 0x5b, 0x54, 0x00, 0x16, 0x72, 0x54, 0x00, 0x01,
+    0x5b, 0x5c, 0x00, 0x0c, 0x24, 0x04, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0xaf, 0x0f, 0x66, 0x6b,
+    0x68, 0xe5, 0x7a, 0xbc, 0x15, 0x4a, 0x9f, 0xb6, 0xdf, 0x6f, 0x7a, 0xe3, 0xcf, 0x9f, 0xad, 0x02,
+    0x77, 0x97, 0x3c, 0x8b, 0x1d, 0x18, 0x21, 0x1c, 0x29, 0xeb, 0x2b, 0xdf,
+];
+
+/// The pair relaying the message at any input, see [`crate::RelayScript::any_input`]
+pub const V3: PredicateVersion = PredicateVersion {
+    version: 3,
+    script: ANY_INPUT_SCRIPT,
+    script_hash: [
+        0xaf, 0x0f, 0x66, 0x6b, 0x68, 0xe5, 0x7a, 0xbc, 0x15, 0x4a, 0x9f, 0xb6, 0xdf, 0x6f, 0x7a,
+        0xe3, 0xcf, 0x9f, 0xad, 0x02, 0x77, 0x97, 0x3c, 0x8b, 0x1d, 0x18, 0x21, 0x1c, 0x29, 0xeb,
+        0x2b, 0xdf,
+    ],
+    predicate: ANY_INPUT_PREDICATE,
+    root: [
+        0x48, 0x4d, 0x0d, 0xca, 0xcb, 0xe7, 0xa6, 0xd3, 0xbb, 0x8a, 0x5d, 0xb6, 0x16, 0xef, 0x45,
+        0x5c, 0x73, 0x82, 0x52, 0x31, 0x5a, 0x1e, 0xa9, 0xb8, 0x72, 0xd6, 0x6b, 0x52, 0x5c, 0x34,
+        0x2e, 0xc9,
+    ],
+};
+
+const VALUE_FORWARDING_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x50, 0x50, 0x45, 0x00, 0x20, 0x61, 0x48, 0x02, 0x48,
+    0x60, 0x45, 0x20, 0x20, 0x50, 0x4c, 0xc0, 0x3c, 0x50, 0x51, 0x10, 0x20, 0x5f, 0x51, 0x30, 0x00,
+    0x50, 0x58, 0xc0, 0x53, 0x50, 0x55, 0x10, 0x28, 0x5f, 0x55, 0x60, 0x00, 0x61, 0x5c, 0x02, 0x42,
+    0x71, 0x60, 0x00, 0x06, 0x2d, 0x45, 0x76, 0x0a, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+const VALUE_FORWARDING_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x4c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x5b, 0x50, 0x10, 0x12, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x59, 0x5d, 0x70, 0x01,
+    0x61, 0x61, 0x72, 0x00, 0x5b, 0x61, 0xa0, 0x10, 0x61, 0x65, 0x72, 0x45, 0x5b, 0x64, 0x00, 0x12,
+    0x5b, 0x5c, 0x10, 0x0b, 0x24, 0x04, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0xc2, 0xea, 0x4b, 0x3b,
+    0x31, 0x1d, 0xfe, 0xc1, 0xa6, 0x46, 0x64, 0x71, 0x8a, 0xd8, 0x09, 0xcf, 0x58, 0x32, 0x4a, 0x91,
+    0x80, 0xa7, 0x8f, 0x2a, 0xd3, 0x2d, 0xed, 0x61, 0xfe, 0xb9, 0xfa, 0x29,
+];
+
+/// The pair forwarding the message value, see [`crate::RelayScript::value_forwarding`]
+pub const V4: PredicateVersion = PredicateVersion {
+    version: 4,
+    script: VALUE_FORWARDING_SCRIPT,
+    script_hash: [
+        0xc2, 0xea, 0x4b, 0x3b, 0x31, 0x1d, 0xfe, 0xc1, 0xa6, 0x46, 0x64, 0x71, 0x8a, 0xd8, 0x09,
+        0xcf, 0x58, 0x32, 0x4a, 0x91, 0x80, 0xa7, 0x8f, 0x2a, 0xd3, 0x2d, 0xed, 0x61, 0xfe, 0xb9,
+        0xfa, 0x29,
+    ],
+    predicate: VALUE_FORWARDING_PREDICATE,
+    root: [
+        0x66, 0x94, 0x98, 0xe2, 0x33, 0xf8, 0x7b, 0x31, 0xc4, 0x16, 0x9a, 0x69, 0x7b, 0x1b, 0x67,
+        0x02, 0x6c, 0x65, 0x72, 0x4c, 0x0b, 0x1f, 0x2d, 0x5f, 0xa3, 0xd5, 0xe2, 0xdb, 0x79, 0xd3,
+        0x3d, 0xc5,
+    ],
+};
+
+const BATCH_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x58, 0x50, 0x45, 0x00, 0x20, 0x50, 0x4c, 0xc0, 0x60,
+    0x50, 0x51, 0x10, 0x20, 0x5f, 0x51, 0x30, 0x00, 0x50, 0x59, 0x10, 0x30, 0x50, 0x55, 0x10, 0x28,
+    0x5f, 0x55, 0x60, 0x00, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x6c, 0x00, 0x02, 0x1a, 0x60, 0x00, 0x00,
+    0x61, 0x65, 0x82, 0x00, 0x5b, 0x65, 0xb0, 0x15, 0x61, 0x69, 0x82, 0x45, 0x13, 0x71, 0xa0, 0x00,
+    0x5b, 0x70, 0x00, 0x15, 0x61, 0x49, 0x82, 0x48, 0x60, 0x45, 0x20, 0x20, 0x5f, 0x59, 0x80, 0x00,
+    0x2d, 0x44, 0x00, 0x0a, 0x50, 0x61, 0x80, 0x01, 0x5b, 0x61, 0x70, 0x0c, 0x24, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+];
+
+const BATCH_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x24, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x24, 0x50, 0x00, 0x00, 0x2c, 0xe9, 0x41, 0x92, 0x52, 0x50, 0x59, 0xd6, 0x1d, 0xff, 0xdc, 0x1f,
+    0x6d, 0x2c, 0xe8, 0xa6, 0x19, 0xcf, 0x23, 0xa0, 0x73, 0xc2, 0x96, 0x09, 0x55, 0xfa, 0x7c, 0x0a,
+    0x42, 0x06, 0x08, 0x7e,
+];
+
+/// The pair relaying every message with data, see [`crate::RelayScript::batch`]
+pub const V5: PredicateVersion = PredicateVersion {
+    version: 5,
+    script: BATCH_SCRIPT,
+    script_hash: [
+        0x2c, 0xe9, 0x41, 0x92, 0x52, 0x50, 0x59, 0xd6, 0x1d, 0xff, 0xdc, 0x1f, 0x6d, 0x2c, 0xe8,
+        0xa6, 0x19, 0xcf, 0x23, 0xa0, 0x73, 0xc2, 0x96, 0x09, 0x55, 0xfa, 0x7c, 0x0a, 0x42, 0x06,
+        0x08, 0x7e,
+    ],
+    predicate: BATCH_PREDICATE,
+    root: [
+        0x8b, 0x8f, 0x3c, 0xb1, 0x30, 0x57, 0x3a, 0x90, 0x73, 0xe9, 0xdf, 0xbc, 0x47, 0xad, 0xb7,
+        0x07, 0x3d, 0xdd, 0x21, 0x46, 0x6a, 0xa4, 0x07, 0xa8, 0xa0, 0xc2, 0x44, 0x97, 0xa1, 0x47,
+        0x94, 0xb0,
+    ],
+};
+
+const REPORTING_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x50, 0x50, 0x45, 0x00, 0x20, 0x61, 0x48, 0x02, 0x48,
+    0x60, 0x45, 0x20, 0x20, 0x50, 0x4c, 0xc0, 0x44, 0x50, 0x51, 0x10, 0x20, 0x5f, 0x51, 0x30, 0x00,
+    0x50, 0x58, 0xc0, 0x5b, 0x50, 0x55, 0x10, 0x28, 0x5f, 0x55, 0x60, 0x00, 0x72, 0x5f, 0xf0, 0x01,
+    0x72, 0x60, 0x00, 0x20, 0x34, 0x5c, 0x04, 0x58, 0x2d, 0x44, 0x00, 0x0a, 0x72, 0x67, 0xf0, 0x02,
+    0x24, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63,
+    0x65, 0x73, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00,
+];
+
+const REPORTING_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x4c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x5b, 0x50, 0x10, 0x12, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x59, 0x5d, 0x70, 0x01,
+    0x61, 0x61, 0x72, 0x00, 0x5b, 0x61, 0xa0, 0x10, 0x61, 0x65, 0x72, 0x45, 0x5b, 0x64, 0x00, 0x12,
+    0x5b, 0x5c, 0x10, 0x0b, 0x24, 0x04, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x71, 0x98, 0xe7, 0xac,
+    0x2f, 0x74, 0x1f, 0xfb, 0x03, 0xb7, 0x85, 0xcd, 0xac, 0x8f, 0x51, 0x19, 0x37, 0x79, 0x03, 0xa0,
+    0x00, 0x1a, 0x0b, 0x75, 0xf5, 0x73, 0x9c, 0x27, 0x0b, 0x80, 0xcf, 0x04,
+];
+
+/// The pair reporting the relay outcome, see [`crate::RelayScript::reporting`]
+pub const V6: PredicateVersion = PredicateVersion {
+    version: 6,
+    script: REPORTING_SCRIPT,
+    script_hash: [
+        0x71, 0x98, 0xe7, 0xac, 0x2f, 0x74, 0x1f, 0xfb, 0x03, 0xb7, 0x85, 0xcd, 0xac, 0x8f, 0x51,
+        0x19, 0x37, 0x79, 0x03, 0xa0, 0x00, 0x1a, 0x0b, 0x75, 0xf5, 0x73, 0x9c, 0x27, 0x0b, 0x80,
+        0xcf, 0x04,
+    ],
+    predicate: REPORTING_PREDICATE,
+    root: [
+        0x1c, 0xcb, 0x7e, 0x4d, 0xfb, 0xd3, 0xd4, 0x24, 0x7d, 0xfb, 0xdd, 0x89, 0x16, 0x31, 0x56,
+        0x8e, 0x39, 0x79, 0x82, 0x9f, 0xfa, 0x69, 0x94, 0x60, 0x43, 0xaf, 0x37, 0xe1, 0x8e, 0x54,
+        0x64, 0x10,
+    ],
+};
+
+const FEE_CAPPED_SCRIPT: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x50, 0x50, 0x45, 0x00, 0x20, 0x61, 0x48, 0x02, 0x48,
+    0x60, 0x45, 0x20, 0x20, 0x50, 0x4c, 0xc0, 0x6c, 0x50, 0x51, 0x10, 0x20, 0x5f, 0x51, 0x30, 0x00,
+    0x50, 0x58, 0xc0, 0x83, 0x50, 0x55, 0x10, 0x28, 0x5f, 0x55, 0x60, 0x00, 0x61, 0x5c, 0x02, 0x42,
+    0x1a, 0x64, 0x00, 0x00, 0x61, 0x68, 0x02, 0x45, 0x72, 0x6c, 0x00, 0x28, 0x16, 0x71, 0xa6, 0xc0,
+    0x5b, 0x70, 0x00, 0x17, 0x10, 0x79, 0x26, 0x80, 0x59, 0x79, 0xe0, 0x08, 0x5d, 0x65, 0xe0, 0x00,
+    0x15, 0x71, 0x95, 0xc0, 0x5b, 0x70, 0x10, 0x17, 0x1a, 0x65, 0x70, 0x00, 0x20, 0x75, 0x76, 0x40,
+    0x71, 0x60, 0x00, 0x06, 0x2d, 0x45, 0xd6, 0x0a, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+const FEE_CAPPED_PREDICATE: &[u8] = &[
+    0x1a, 0x40, 0x50, 0x00, 0x91, 0x00, 0x00, 0x20, 0x61, 0x44, 0x00, 0x09, 0x61, 0x48, 0x00, 0x03,
+    0x42, 0x41, 0x14, 0x80, 0x50, 0x4c, 0xc0, 0x4c, 0x72, 0x58, 0x00, 0x20, 0x29, 0x51, 0x34, 0x16,
+    0x5b, 0x50, 0x10, 0x12, 0x61, 0x5c, 0x00, 0x05, 0x72, 0x68, 0x00, 0x02, 0x59, 0x5d, 0x70, 0x01,
+    0x61, 0x61, 0x72, 0x00, 0x5b, 0x61, 0xa0, 0x10, 0x61, 0x65, 0x72, 0x45, 0x5b, 0x64, 0x00, 0x12,
+    0x5b, 0x5c, 0x10, 0x0b, 0x24, 0x04, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x29, 0xe4, 0x2a, 0x5e,
+    0xd1, 0xc1, 0x09, 0x76, 0x52, 0xb8, 0xd7, 0x1f, 0xde, 0x21, 0x91, 0x80, 0x69, 0x22, 0x34, 0x13,
+    0x2c, 0x8a, 0x3a, 0x37, 0x72, 0x04, 0x84, 0x13, 0x27, 0xd8, 0x2c, 0x1f,
+];
+
+/// The pair reimbursing the relayer, see [`crate::RelayScript::fee_capped`]
+pub const V7: PredicateVersion = PredicateVersion {
+    version: 7,
+    script: FEE_CAPPED_SCRIPT,
+    script_hash: [
+        0x29, 0xe4, 0x2a, 0x5e, 0xd1, 0xc1, 0x09, 0x76, 0x52, 0xb8, 0xd7, 0x1f, 0xde, 0x21, 0x91,
+        0x80, 0x69, 0x22, 0x34, 0x13, 0x2c, 0x8a, 0x3a, 0x37, 0x72, 0x04, 0x84, 0x13, 0x27, 0xd8,
+        0x2c, 0x1f,
+    ],
+    predicate: FEE_CAPPED_PREDICATE,
+    root: [
+        0x42, 0x14, 0x7e, 0x15, 0xf9, 0x0e, 0x56, 0xe0, 0x6f, 0xdf, 0xd6, 0x9a, 0xa4, 0x71, 0x8a,
+        0x68, 0xf0, 0xaa, 0x96, 0x72, 0x58, 0x61, 0x34, 0x0d, 0x8a, 0x4a, 0x16, 0x76, 0x32, 0xfd,
+        0x3b, 0x7a,
+    ],
+};
+
+/// Every released version, oldest first. A change to the generators must be
+/// released as a new version appended here.
+pub const VERSIONS: &[PredicateVersion] = &[V1, V2, V3, V4, V5, V6, V7];
+
+// Gets the version of the default predicate in the current build artifacts
+pub fn current() -> &'static PredicateVersion {
+    VERSIONS
+        .iter()
+        .find(|version| version.predicate == crate::PREDICATE_BYTECODE)
+        .expect("The default predicate is released")
+}
+
+// Gets the released version whose predicate has the given root, if any
+pub fn version_for_root(root: Address) -> Option<&'static PredicateVersion> {
+    VERSIONS
+        .iter()
+        .find(|version| version.predicate_owner() == root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_consistent() {
+        for (index, version) in VERSIONS.iter().enumerate() {
+            assert_eq!(usize::from(version.version), index + 1);
+            assert!(version.is_consistent(), "version {}", version.version);
+        }
+    }
+
+    #[test]
+    fn finds_version_by_root() {
+        let root = Address::from(crate::predicate_root());
+        assert_eq!(version_for_root(root), Some(current()));
+        let root = Address::from(crate::predicate_v2_root());
+        assert_eq!(version_for_root(root), Some(&V2));
+        assert_eq!(version_for_root(Address::zeroed()), None);
+    }
+
+    #[test]
+    fn variants_are_released() {
+        for (relay, version) in [
+            (crate::RelayScript::any_input(), &V3),
+            (crate::RelayScript::value_forwarding(), &V4),
+            (crate::RelayScript::batch(), &V5),
+            (crate::RelayScript::reporting(), &V6),
+            (crate::RelayScript::fee_capped(), &V7),
+        ] {
+            assert_eq!(relay.script, version.script);
+            assert_eq!(relay.predicate, version.predicate);
+            let root = Address::from(relay.predicate_root());
+            assert_eq!(version_for_root(root), Some(version));
+        }
+    }
+}
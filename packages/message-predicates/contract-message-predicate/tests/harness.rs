@@ -9,9 +9,12 @@ mod success {
     use std::str::FromStr;
 
     use crate::utils::{builder, environment as env};
-    use fuel_tx::Bytes32;
+    use fuel_contract_message_predicate::{
+        codegen::script_asm::RELAY_DELIVERED, outcome::RelayOutcome, RelayScript, RelayTxBuilder,
+    };
+    use fuel_tx::{Bytes32, Receipt};
     use fuels::{
-        prelude::{Address, AssetId, ContractId},
+        prelude::{Address, AssetId, ContractId, TxPolicies},
         programs::calls::Execution,
         test_helpers::DEFAULT_COIN_AMOUNT,
         types::Bits256,
@@ -138,6 +141,389 @@ mod success {
             .value;
         assert_eq!(counter, prev_counter + 1);
     }
+
+    #[tokio::test]
+    async fn relay_message_at_any_input_index() {
+        let relay = RelayScript::any_input();
+        let data_word = RANDOM_WORD;
+        let data_bytes = Bits256(Bytes32::from_str(RANDOM_SALT).unwrap().into());
+        let data_address = Address::from_str(RANDOM_SALT2).unwrap();
+
+        let message_data = env::message_data(data_word, RANDOM_SALT, RANDOM_SALT2).await;
+        let message1: (u64, Vec<u8>) = (200, vec![]);
+        let message2: (u64, Vec<u8>) = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message1, message2],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let methods = test_contract.methods();
+
+        // The message with data goes last, after the gas coin and the contract
+        let tx = RelayTxBuilder::from_inputs(vec![
+            coin_inputs[0].clone(),
+            contract_input.clone(),
+            message_inputs[0].clone(),
+            message_inputs[1].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        let _tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // Verify test contract received the message with the correct data
+        let test_contract_counter = methods.test_counter().call().await.unwrap().value;
+        let test_contract_data1 = methods.test_data1().call().await.unwrap().value;
+        let test_contract_data2 = methods.test_data2().call().await.unwrap().value;
+        let test_contract_data3 = methods.test_data3().call().await.unwrap().value;
+        let test_contract_data4 = methods.test_data4().call().await.unwrap().value;
+        assert_eq!(test_contract_counter, 1);
+        assert_eq!(test_contract_data1, test_contract_id);
+        assert_eq!(test_contract_data2, data_word);
+        assert_eq!(test_contract_data3, data_bytes);
+        assert_eq!(test_contract_data4, data_address);
+    }
+
+    #[tokio::test]
+    async fn relay_message_forwarding_value_to_contract() {
+        let relay = RelayScript::value_forwarding();
+        let message_amount = 100;
+
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (message_amount, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let prev_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        let _tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // Verify the test contract processed the message and received its value
+        let test_contract_counter = test_contract
+            .methods()
+            .test_counter()
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(test_contract_counter, 1);
+
+        let balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(balance, prev_balance + message_amount);
+    }
+
+    #[tokio::test]
+    async fn relay_message_reimbursing_relayer_fee() {
+        let relay = RelayScript::fee_capped();
+        let message_amount = 100;
+        let relayer_fee = 30u64;
+
+        // The relayer fee is the last word of the data, after the receiver payload
+        let mut message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        message_data.extend_from_slice(&relayer_fee.to_be_bytes());
+        let message = (message_amount, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let prev_balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        let _tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // Verify the test contract processed the unchanged message data and
+        // received its value minus the relayer fee
+        let methods = test_contract.methods();
+        let test_contract_counter = methods.test_counter().call().await.unwrap().value;
+        let test_contract_data2 = methods.test_data2().call().await.unwrap().value;
+        assert_eq!(test_contract_counter, 1);
+        assert_eq!(test_contract_data2, RANDOM_WORD);
+
+        let balance = provider
+            .get_contract_asset_balance(test_contract.contract_id(), AssetId::default())
+            .await
+            .unwrap();
+        assert_eq!(balance, prev_balance + message_amount - relayer_fee);
+    }
+
+    #[tokio::test]
+    async fn relay_batch_of_messages() {
+        let relay = RelayScript::batch();
+        let message_data1 = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message_data2 = env::message_data(RANDOM_WORD2, RANDOM_SALT2, RANDOM_SALT).await;
+        let message1: (u64, Vec<u8>) = (100, message_data1);
+        let message2: (u64, Vec<u8>) = (200, vec![]);
+        let message3: (u64, Vec<u8>) = (150, message_data2);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message1, message2, message3],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let methods = test_contract.methods();
+
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+            message_inputs[1].clone(),
+            message_inputs[2].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        let tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // Verify the test contract was called once per message with data
+        let receipts = provider.tx_status(&tx_id).await.unwrap().take_receipts();
+        let calls = receipts
+            .iter()
+            .filter(
+                |receipt| matches!(receipt, Receipt::Call { to, .. } if *to == test_contract_id),
+            )
+            .count();
+        assert_eq!(calls, 2);
+
+        // The last message with data is the last one processed
+        let test_contract_data1 = methods.test_data1().call().await.unwrap().value;
+        let test_contract_data2 = methods.test_data2().call().await.unwrap().value;
+        assert_eq!(test_contract_data1, test_contract_id);
+        assert_eq!(test_contract_data2, RANDOM_WORD2);
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_predicate_v2() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, _, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                fuel_contract_message_predicate::predicate_v2_bytecode(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let methods = test_contract.methods();
+
+        let tx = builder::build_contract_message_tx(
+            message_inputs[0].clone(),
+            std::slice::from_ref(&contract_input),
+            &[],
+            &wallet,
+        )
+        .await;
+
+        provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // Verify test contract received the message
+        let test_contract_counter = methods.test_counter().call().await.unwrap().value;
+        let test_contract_data1 = methods.test_data1().call().await.unwrap().value;
+        assert_eq!(test_contract_counter, 1);
+        assert_eq!(test_contract_data1, test_contract_id);
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_reporting_script() {
+        let relay = RelayScript::reporting();
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        let tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        let receipts = provider.tx_status(&tx_id).await.unwrap().take_receipts();
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::Delivered {
+                contract_id: Some(test_contract_id)
+            }
+        );
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Return { id, val, .. }
+                if *id == ContractId::zeroed() && *val == u64::from(RELAY_DELIVERED)
+        )));
+    }
+
+    #[tokio::test]
+    async fn relay_message_discovering_contracts() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, _, _, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+        let gas_coins = provider
+            .get_coins(wallet.address(), Default::default())
+            .await
+            .unwrap();
+
+        // The builder starts without any contract input
+        let mut builder = RelayTxBuilder::new(message_inputs[0].clone())
+            .with_gas_coins(&gas_coins)
+            .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)));
+        let discovered = builder.discover_contracts(&wallet, 3).await.unwrap();
+        assert_eq!(discovered, vec![test_contract_id]);
+
+        let tx = builder.build(&wallet).await.unwrap();
+        let _tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        let test_contract_data1 = test_contract
+            .methods()
+            .test_data1()
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(test_contract_data1, test_contract_id);
+    }
+
+    #[tokio::test]
+    async fn relay_message_with_estimated_fees() {
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, _, message_inputs) =
+            env::setup_environment(vec![coin], vec![message]).await;
+        let provider = wallet.provider().unwrap();
+
+        let gas_coins = provider
+            .get_coins(wallet.address(), Default::default())
+            .await
+            .unwrap();
+        let builder = RelayTxBuilder::new(message_inputs[0].clone())
+            .with_contracts(vec![contract_input])
+            .with_gas_coins(&gas_coins);
+
+        let estimate = builder.estimate_fees(&wallet, 10).await.unwrap();
+        let tx = builder
+            .with_tx_policies(estimate.apply(TxPolicies::default()))
+            .build(&wallet)
+            .await
+            .unwrap();
+        let tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        // The script fits in the estimated gas limit
+        let receipts = provider.tx_status(&tx_id).await.unwrap().take_receipts();
+        let gas_used = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap();
+        assert!(gas_used <= estimate.script_gas_limit);
+    }
 }
 
 // Test the cases where the transaction should panic due to the
@@ -147,10 +533,14 @@ mod fail {
 
     use crate::utils::{builder, environment as env};
 
+    use fuel_contract_message_predicate::{
+        outcome::{RelayFailure, RelayOutcome},
+        validate_relay_tx, RelayScript, RelayTxBuilder, RelayTxError,
+    };
     use fuel_tx::{PanicReason, Receipt};
     use fuels::{
         accounts::Account,
-        prelude::{Address, AssetId, Salt, TxPolicies},
+        prelude::{Address, AssetId, ContractId, Salt, TxPolicies},
         test_helpers::DEFAULT_COIN_AMOUNT,
         types::{
             coin::{Coin, CoinStatus::Unspent},
@@ -209,7 +599,7 @@ mod fail {
 
         let tx = builder::build_contract_message_tx(
             coin_as_message,
-            &vec![contract_input.clone()],
+            std::slice::from_ref(&contract_input),
             &[],
             &wallet,
         )
@@ -263,6 +653,46 @@ mod fail {
         );
     }
 
+    #[tokio::test]
+    async fn relay_message_with_reporting_script_and_missing_contract() {
+        let relay = RelayScript::reporting();
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, test_contract, _, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let test_contract_id: ContractId = test_contract.contract_id().into();
+
+        let tx =
+            RelayTxBuilder::from_inputs(vec![message_inputs[0].clone(), coin_inputs[0].clone()])
+                .with_script(relay.script.clone())
+                .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+                .build(&wallet)
+                .await
+                .unwrap();
+
+        let tx_id = provider
+            .send_transaction(tx)
+            .await
+            .expect("Transaction failed");
+
+        let receipts = provider.tx_status(&tx_id).await.unwrap().take_receipts();
+        assert_eq!(
+            RelayOutcome::from_receipts(&receipts),
+            RelayOutcome::ScriptFailed {
+                contract_id: Some(test_contract_id),
+                reason: RelayFailure::Panic(PanicReason::ContractNotInInputs)
+            }
+        );
+    }
+
     #[tokio::test]
     async fn relay_message_with_wrong_contract() {
         let message_data_bad = Salt::from_str(RANDOM_SALT).unwrap().to_vec();
@@ -274,7 +704,7 @@ mod fail {
 
         let tx = builder::build_contract_message_tx(
             message_inputs[0].clone(),
-            &vec![contract_input.clone()],
+            std::slice::from_ref(&contract_input),
             &[],
             &wallet,
         )
@@ -313,7 +743,7 @@ mod fail {
 
         let tx = builder::build_contract_message_tx(
             message_inputs[0].clone(),
-            &vec![
+            &[
                 message_inputs[1].clone(),
                 message_inputs[2].clone(),
                 contract_input.clone(),
@@ -322,6 +752,51 @@ mod fail {
             &wallet,
         )
         .await;
+        assert_eq!(
+            validate_relay_tx(&tx),
+            Err(RelayTxError::ExtraMessageData { index: 2 })
+        );
+
+        match provider.send_transaction(tx).await.unwrap_err() {
+            fuels::types::errors::Error::Transaction(error) => {
+                let stringified_error = error.to_string();
+                let expected_error = String::from(
+                    "validation: PredicateVerificationFailed(Panic(PredicateReturnedNonOne))",
+                );
+                assert_eq!(stringified_error, expected_error);
+            }
+            _ => unreachable!("Test threw an unexpected error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn relay_multiple_messages_at_any_input_index() {
+        let relay = RelayScript::any_input();
+        let message_data1 = env::message_data(RANDOM_WORD, RANDOM_SALT3, RANDOM_SALT).await;
+        let message1 = (100, message_data1);
+        let message_data2 = env::message_data(RANDOM_WORD2, RANDOM_SALT2, RANDOM_SALT3).await;
+        let message2 = (150, message_data2);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message1, message2],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        let tx = RelayTxBuilder::from_inputs(vec![
+            coin_inputs[0].clone(),
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            message_inputs[1].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
 
         match provider.send_transaction(tx).await.unwrap_err() {
             fuels::types::errors::Error::Transaction(error) => {
@@ -346,11 +821,95 @@ mod fail {
 
         let tx = builder::build_invalid_contract_message_tx(
             message_inputs[0].clone(),
-            &vec![contract_input.clone()],
+            std::slice::from_ref(&contract_input),
             &[],
             &wallet,
         )
         .await;
+        assert!(matches!(
+            validate_relay_tx(&tx),
+            Err(RelayTxError::WrongScriptHash { .. })
+        ));
+
+        match provider.send_transaction(tx).await.unwrap_err() {
+            fuels::types::errors::Error::Transaction(error) => {
+                let stringified_error = error.to_string();
+                let expected_error = String::from(
+                    "validation: PredicateVerificationFailed(Panic(PredicateReturnedNonOne))",
+                );
+                assert_eq!(stringified_error, expected_error);
+            }
+            _ => unreachable!("Test threw an unexpected error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn relay_batch_of_messages_with_other_script() {
+        let relay = RelayScript::batch();
+        let message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        // The default relay script must not be able to spend batch messages
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(fuel_contract_message_predicate::script_bytecode())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        match provider.send_transaction(tx).await.unwrap_err() {
+            fuels::types::errors::Error::Transaction(error) => {
+                let stringified_error = error.to_string();
+                let expected_error = String::from(
+                    "validation: PredicateVerificationFailed(Panic(PredicateReturnedNonOne))",
+                );
+                assert_eq!(stringified_error, expected_error);
+            }
+            _ => unreachable!("Test threw an unexpected error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn relay_message_keeping_more_than_relayer_fee() {
+        let relay = RelayScript::fee_capped();
+        let mut message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        message_data.extend_from_slice(&30u64.to_be_bytes());
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        // A greedy relayer using the default script, which forwards none of the
+        // message value, must not be able to spend fee-capped messages
+        let tx = RelayTxBuilder::from_inputs(vec![
+            message_inputs[0].clone(),
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(fuel_contract_message_predicate::script_bytecode())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
 
         match provider.send_transaction(tx).await.unwrap_err() {
             fuels::types::errors::Error::Transaction(error) => {
@@ -363,4 +922,55 @@ mod fail {
             _ => unreachable!("Test threw an unexpected error"),
         }
     }
+
+    #[tokio::test]
+    async fn relay_message_with_predicate_disagreeing_on_relayer_fee() {
+        let relay = RelayScript::fee_capped();
+        let mut message_data = env::message_data(RANDOM_WORD, RANDOM_SALT, RANDOM_SALT2).await;
+        message_data.extend_from_slice(&30u64.to_be_bytes());
+        let message = (100, message_data);
+        let coin = (DEFAULT_COIN_AMOUNT, AssetId::default());
+        let (wallet, _, contract_input, coin_inputs, message_inputs) =
+            env::setup_environment_with_predicate(
+                vec![coin],
+                vec![message],
+                relay.predicate.clone(),
+            )
+            .await;
+        let provider = wallet.provider().unwrap();
+
+        // A greedy relayer running the fee-capped script must not be able to
+        // spend the message through a predicate carrying a higher fee than the
+        // one in the message data read by the script
+        let mut greedy_predicate = relay.predicate.clone();
+        greedy_predicate.extend_from_slice(&100u64.to_be_bytes());
+        let greedy_message = match message_inputs[0].clone() {
+            Input::ResourcePredicate { resource, data, .. } => Input::ResourcePredicate {
+                resource,
+                code: greedy_predicate,
+                data,
+            },
+            _ => unreachable!("Messages are spent through the predicate"),
+        };
+        let tx = RelayTxBuilder::from_inputs(vec![
+            greedy_message,
+            contract_input.clone(),
+            coin_inputs[0].clone(),
+        ])
+        .with_script(relay.script.clone())
+        .with_tx_policies(TxPolicies::new(Some(0), None, None, None, Some(300_000)))
+        .build(&wallet)
+        .await
+        .unwrap();
+
+        match provider.send_transaction(tx).await.unwrap_err() {
+            fuels::types::errors::Error::Transaction(error) => {
+                let stringified_error = error.to_string();
+                let expected_error =
+                    String::from("validation: Validity(InputPredicateOwner { index: 0 })");
+                assert_eq!(stringified_error, expected_error);
+            }
+            _ => unreachable!("Test threw an unexpected error"),
+        }
+    }
 }
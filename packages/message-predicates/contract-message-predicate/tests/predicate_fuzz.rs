@@ -0,0 +1,255 @@
+// Property tests checking that the contract message predicate accepts a
+// transaction exactly when it runs the relay script and no input other than
+// the first one is a message with data, and that the gas-optimized predicate
+// agrees with it when the first input is a message
+use fuel_contract_message_predicate::{
+    evaluate::{evaluate_predicates, EvaluateError},
+    predicate_bytecode, predicate_v2_bytecode,
+};
+use fuel_tx::{
+    Address, AssetId, Bytes32, ConsensusParameters, ContractId, Input, Output, Script,
+    TransactionBuilder, TxPointer, UtxoId, Witness,
+};
+use fuel_vm::fuel_types::Nonce;
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+enum InputKind {
+    Coin,
+    Contract,
+    PredicateMessage { data: Vec<u8> },
+    SignedMessage { data: Vec<u8> },
+}
+
+impl InputKind {
+    fn is_message(&self) -> bool {
+        matches!(
+            self,
+            Self::PredicateMessage { .. } | Self::SignedMessage { .. }
+        )
+    }
+
+    fn has_message_data(&self) -> bool {
+        match self {
+            Self::PredicateMessage { data } | Self::SignedMessage { data } => !data.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OutputKind {
+    // index of the contract input among all contract inputs
+    Contract(usize),
+    Coin,
+    Variable,
+    Change,
+}
+
+#[derive(Debug, Clone)]
+enum ScriptKind {
+    Relay,
+    // relay script with a single byte flipped
+    Mutated { index: usize, mask: u8 },
+    Foreign(Vec<u8>),
+}
+
+impl ScriptKind {
+    fn bytecode(&self) -> Vec<u8> {
+        let mut script = fuel_contract_message_predicate::script_bytecode();
+        match self {
+            Self::Relay => script,
+            Self::Mutated { index, mask } => {
+                let index = index % script.len();
+                script[index] ^= mask;
+                script
+            }
+            Self::Foreign(bytes) => bytes.clone(),
+        }
+    }
+}
+
+fn input_kind() -> impl Strategy<Value = InputKind> {
+    let data = prop_oneof![Just(vec![]), prop::collection::vec(any::<u8>(), 1..96),];
+    prop_oneof![
+        Just(InputKind::Coin),
+        Just(InputKind::Contract),
+        data.clone()
+            .prop_map(|data| InputKind::PredicateMessage { data }),
+        data.prop_map(|data| InputKind::SignedMessage { data }),
+    ]
+}
+
+fn script_kind() -> impl Strategy<Value = ScriptKind> {
+    prop_oneof![
+        2 => Just(ScriptKind::Relay),
+        1 => (any::<usize>(), 1..=u8::MAX)
+            .prop_map(|(index, mask)| ScriptKind::Mutated { index, mask }),
+        1 => prop::collection::vec(any::<u8>(), 0..128).prop_map(ScriptKind::Foreign),
+    ]
+}
+
+// Random inputs, always including a predicate message and a coin to pay for
+// the transaction, along with outputs covering every contract input
+fn layout() -> impl Strategy<Value = (Vec<InputKind>, Vec<OutputKind>)> {
+    (
+        prop::collection::vec(input_kind(), 0..8),
+        prop::collection::vec(any::<u8>(), 0..96),
+        any::<prop::sample::Index>(),
+        any::<prop::sample::Index>(),
+    )
+        .prop_map(|(mut inputs, data, message_at, coin_at)| {
+            let message_at = message_at.index(inputs.len() + 1);
+            inputs.insert(message_at, InputKind::PredicateMessage { data });
+            let coin_at = coin_at.index(inputs.len() + 1);
+            inputs.insert(coin_at, InputKind::Coin);
+            inputs
+        })
+        .prop_flat_map(|inputs| {
+            let contracts = inputs
+                .iter()
+                .filter(|input| matches!(input, InputKind::Contract))
+                .count();
+            let outputs = (
+                prop::collection::vec(
+                    prop_oneof![Just(OutputKind::Coin), Just(OutputKind::Variable)],
+                    0..4,
+                ),
+                any::<bool>(),
+            )
+                .prop_map(move |(mut outputs, change)| {
+                    outputs.extend((0..contracts).map(OutputKind::Contract));
+                    if change {
+                        outputs.push(OutputKind::Change);
+                    }
+                    outputs
+                })
+                .prop_shuffle();
+            (Just(inputs), outputs)
+        })
+}
+
+fn build_tx(
+    script: Vec<u8>,
+    predicate: Vec<u8>,
+    inputs: &[InputKind],
+    outputs: &[OutputKind],
+) -> Script {
+    let predicate_root = Input::predicate_owner(&predicate);
+
+    let mut builder = TransactionBuilder::script(script, vec![]);
+    builder.script_gas_limit(1_000_000);
+    builder.add_witness(Witness::default());
+
+    let mut contract_inputs = vec![];
+    for (index, kind) in inputs.iter().enumerate() {
+        let seed = [index as u8 + 1; 32];
+        let input = match kind {
+            InputKind::Coin => Input::coin_signed(
+                UtxoId::new(seed.into(), 0),
+                Address::zeroed(),
+                1_000_000,
+                AssetId::BASE,
+                TxPointer::default(),
+                0,
+            ),
+            InputKind::Contract => {
+                contract_inputs.push(index as u16);
+                Input::contract(
+                    UtxoId::new(seed.into(), 0),
+                    Bytes32::zeroed(),
+                    Bytes32::zeroed(),
+                    TxPointer::default(),
+                    ContractId::from(seed),
+                )
+            }
+            InputKind::PredicateMessage { data } if data.is_empty() => {
+                Input::message_coin_predicate(
+                    Address::zeroed(),
+                    predicate_root,
+                    100,
+                    Nonce::from(seed),
+                    0,
+                    predicate.clone(),
+                    vec![],
+                )
+            }
+            InputKind::PredicateMessage { data } => Input::message_data_predicate(
+                Address::zeroed(),
+                predicate_root,
+                100,
+                Nonce::from(seed),
+                0,
+                data.clone(),
+                predicate.clone(),
+                vec![],
+            ),
+            InputKind::SignedMessage { data } if data.is_empty() => Input::message_coin_signed(
+                Address::zeroed(),
+                Address::zeroed(),
+                100,
+                Nonce::from(seed),
+                0,
+            ),
+            InputKind::SignedMessage { data } => Input::message_data_signed(
+                Address::zeroed(),
+                Address::zeroed(),
+                100,
+                Nonce::from(seed),
+                0,
+                data.clone(),
+            ),
+        };
+        builder.add_input(input);
+    }
+
+    for kind in outputs {
+        let output = match kind {
+            OutputKind::Contract(index) => Output::contract(
+                contract_inputs[*index],
+                Bytes32::zeroed(),
+                Bytes32::zeroed(),
+            ),
+            OutputKind::Coin => Output::coin(Address::zeroed(), 0, AssetId::BASE),
+            OutputKind::Variable => Output::variable(Address::zeroed(), 0, AssetId::zeroed()),
+            OutputKind::Change => Output::change(Address::zeroed(), 0, AssetId::BASE),
+        };
+        builder.add_output(output);
+    }
+
+    builder.finalize_without_signature_inner()
+}
+
+proptest! {
+    #[test]
+    fn predicate_accepts_only_relay_layouts(
+        script in script_kind(),
+        (inputs, outputs) in layout(),
+    ) {
+        let script = script.bytecode();
+
+        let expected = script == fuel_contract_message_predicate::script_bytecode()
+            && !inputs.iter().skip(1).any(InputKind::has_message_data);
+
+        // the gas-optimized predicate also requires the first input to be a message
+        let expected_v2 = expected && inputs[0].is_message();
+
+        for (predicate, expected) in [
+            (predicate_bytecode(), expected),
+            (predicate_v2_bytecode(), expected_v2),
+        ] {
+            let tx = build_tx(script.clone(), predicate, &inputs, &outputs);
+
+            match evaluate_predicates(&tx, &ConsensusParameters::standard()) {
+                Ok(gas_used) => {
+                    prop_assert!(expected, "accepted with inputs {:?}", inputs);
+                    prop_assert!(gas_used > 0);
+                }
+                Err(EvaluateError::PredicateFailed(e)) => {
+                    prop_assert!(!expected, "rejected with {:?} for inputs {:?}", e, inputs);
+                }
+                Err(e) => prop_assert!(false, "invalid transaction: {:?}", e),
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::{mem::size_of, num::ParseIntError, str::FromStr, vec};
+use std::{mem::size_of, num::ParseIntError, str::FromStr};
 
 use fuel_core_types::fuel_vm::SecretKey;
 use fuels::{
@@ -7,10 +7,11 @@ use fuels::{
         abigen, setup_custom_assets_coins, Address, AssetConfig, AssetId, Contract,
         LoadConfiguration, TxPolicies,
     },
-    test_helpers::{setup_single_message, setup_test_provider},
+    test_helpers::setup_test_provider,
     types::{coin_type::CoinType, input::Input, message::Message},
 };
 
+use fuel_contract_message_predicate::message::{portal_nonce, BridgeMessage};
 use fuel_tx::{Bytes32, TxId, TxPointer, UtxoId, Word};
 
 use super::builder;
@@ -34,6 +35,23 @@ pub async fn setup_environment(
     Input,
     Vec<Input>,
     Vec<Input>,
+) {
+    let predicate_bytecode = fuel_contract_message_predicate::predicate_bytecode();
+    setup_environment_with_predicate(coins, messages, predicate_bytecode).await
+}
+
+/// Sets up a test fuel environment with a funded wallet, sending the messages
+/// to the given predicate instead of the default one
+pub async fn setup_environment_with_predicate(
+    coins: Vec<(Word, AssetId)>,
+    messages: Vec<(Word, Vec<u8>)>,
+    predicate_bytecode: Vec<u8>,
+) -> (
+    WalletUnlocked,
+    TestContract<WalletUnlocked>,
+    Input,
+    Vec<Input>,
+    Vec<Input>,
 ) {
     // Create secret for wallet
     const SIZE_SECRET_KEY: usize = size_of::<SecretKey>();
@@ -61,20 +79,20 @@ pub async fn setup_environment(
 
     // Generate messages
     let message_sender = Address::from_str(MESSAGE_SENDER_ADDRESS).unwrap();
-    let predicate_bytecode = fuel_contract_message_predicate::predicate_bytecode();
-    let predicate_root = Address::from(fuel_contract_message_predicate::predicate_root());
+    let predicate_root = fuel_tx::Input::predicate_owner(&predicate_bytecode);
 
     let all_messages: Vec<Message> = messages
         .iter()
         .enumerate()
-        .flat_map(|(counter, message)| {
-            vec![setup_single_message(
-                &message_sender.into(),
-                &predicate_root.into(),
-                message.0,
-                (counter as u64).into(),
-                message.1.clone(),
-            )]
+        .map(|(counter, message)| {
+            BridgeMessage {
+                sender: message_sender,
+                recipient: predicate_root,
+                nonce: portal_nonce(counter as u64),
+                amount: message.0,
+                data: message.1.clone(),
+            }
+            .into()
         })
         .collect();
 
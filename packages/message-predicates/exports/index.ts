@@ -1,5 +1,7 @@
 import _contractMessagePredicate from '../contract-message-predicate/out/contract_message_predicate.bin';
+import _contractMessagePredicateV2 from '../contract-message-predicate/out/contract_message_predicate_v2.bin';
 import _contractMessageScript from '../contract-message-predicate/out/contract_message_script.bin';
 
 export const contractMessagePredicate = _contractMessagePredicate;
+export const contractMessagePredicateV2 = _contractMessagePredicateV2;
 export const contractMessageScript = _contractMessageScript;